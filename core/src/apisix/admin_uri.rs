@@ -0,0 +1,216 @@
+use super::{
+    common::prelude::*, consumer_groups::ConsumerGroup, consumers::Consumer,
+    promotion::PromotionEntity, routes::Route, secrets::Secret, services::Service,
+    stream_routes::StreamRoute, upstreams::Upstream,
+};
+
+// Filters and pagination of the list endpoints
+const LIST_QUERY: &[&str] = &["label", "name", "page", "page_size", "uri"];
+
+// A resource of the admin API, followed by the min and max number of id segments. A `*` in the
+// prefix matches the id of the parent resource
+struct AdminResource {
+    prefix: &'static str,
+    ids: (usize, usize),
+    // Parameters accepted with the min number of ids, like the filters of the entity lists
+    query: &'static [&'static str],
+}
+
+impl AdminResource {
+    // Lists with the prefix, and single items with `{prefix}/{id}`
+    const fn entity(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            ids: (0, 1),
+            query: LIST_QUERY,
+        }
+    }
+}
+
+fn admin_resources() -> Vec<AdminResource> {
+    let entity_prefixes = [
+        ConsumerGroup::API_PREFIX,
+        Consumer::API_PREFIX,
+        PromotionEntity::PluginConfig.api_prefix(),
+        Route::API_PREFIX,
+        Secret::API_PREFIX,
+        Service::API_PREFIX,
+        StreamRoute::API_PREFIX,
+        Upstream::API_PREFIX,
+        "/global_rules",
+        "/protos",
+        "/ssls",
+        // Available since APISIX 3.7
+        "/consumers/*/credentials",
+    ];
+
+    let mut resources: Vec<_> = entity_prefixes
+        .into_iter()
+        .map(AdminResource::entity)
+        .collect();
+
+    resources.extend([
+        // Every plugin with its schema when `all=true`
+        AdminResource {
+            prefix: "/plugins",
+            ids: (0, 0),
+            query: &["all", "subsystem"],
+        },
+        AdminResource {
+            prefix: "/plugins/list",
+            ids: (0, 0),
+            query: &["subsystem"],
+        },
+        // The schema of a plugin
+        AdminResource {
+            prefix: "/plugins",
+            ids: (1, 1),
+            query: &["subsystem"],
+        },
+        AdminResource {
+            prefix: "/plugin_metadata",
+            ids: (1, 1),
+            query: &[],
+        },
+    ]);
+
+    resources
+}
+
+// Same characters as the ids and names accepted by APISIX, so there are no `..` or encoded paths
+pub(super) fn is_valid_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment != "."
+        && segment != ".."
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn validate_query(query: &str, allowed: &[&str]) -> Result<(), String> {
+    for pair in query.split('&') {
+        let key = pair.split_once('=').map_or(pair, |(k, _)| k);
+
+        if !allowed.contains(&key) {
+            return Err(format!("The query parameter {key:?} is not allowed"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the uri is a known resource of the admin API before proxying it
+pub fn validate_admin_uri(uri: &str) -> Result<(), String> {
+    let (path, query) = match uri.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (uri, None),
+    };
+
+    let segments: Vec<&str> = path
+        .strip_prefix('/')
+        .ok_or_else(|| format!("The uri {uri:?} must start with /"))?
+        .split('/')
+        .collect();
+
+    if !segments.iter().all(|s| is_valid_segment(s)) {
+        return Err(format!("The uri {uri:?} has an invalid path"));
+    }
+
+    let (resource, ids) = admin_resources()
+        .into_iter()
+        .find_map(|r| {
+            let prefix: Vec<&str> = r.prefix[1..].split('/').collect();
+            let ids = segments.len().saturating_sub(prefix.len());
+            let is_prefix = segments.len() >= prefix.len()
+                && prefix
+                    .iter()
+                    .zip(&segments)
+                    .all(|(p, s)| *p == "*" || p == s);
+            let is_match = is_prefix && ids >= r.ids.0 && ids <= r.ids.1;
+
+            is_match.then_some((r, ids))
+        })
+        .ok_or_else(|| format!("The uri {uri:?} is not a known resource of the admin API"))?;
+
+    match query {
+        Some(query) if ids == resource.ids.0 => validate_query(query, resource.query),
+        Some(_) => Err(format!("The uri {uri:?} doesn't accept query parameters")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_resources() {
+        for uri in [
+            "/routes",
+            "/routes/1",
+            "/routes?page=1&page_size=10",
+            "/consumers/jack",
+            "/consumers/jack/credentials",
+            "/consumers/jack/credentials/key-1",
+            "/secrets/vault/my.id",
+            "/plugin_configs/a-b",
+            "/plugins?all=true",
+            "/plugins?all=true&subsystem=stream",
+            "/plugins/list",
+            "/plugins/list?subsystem=stream",
+            "/plugins/limit-count",
+            "/plugin_metadata/http-logger",
+            "/ssls/1",
+            "/stream_routes/2",
+        ] {
+            assert_eq!(validate_admin_uri(uri), Ok(()), "{uri}");
+        }
+    }
+
+    #[test]
+    fn test_traversal() {
+        for uri in [
+            "/routes/..%2f",
+            "/routes/../ssls",
+            "/routes/%2e%2e/ssls",
+            "/routes/./1",
+            "/consumers/../credentials",
+            "/consumers/jack/credentials/..",
+            "/routes//1",
+            "/routes/",
+            "routes",
+            "/",
+        ] {
+            assert!(validate_admin_uri(uri).is_err(), "{uri}");
+        }
+    }
+
+    #[test]
+    fn test_query() {
+        for uri in [
+            "/routes?foo=1",
+            "/routes/1?page=1",
+            "/plugins/limit-count?all=true",
+            "/plugin_metadata/http-logger?all=true",
+            "/consumers/jack/credentials/key-1?page=1",
+            "/routes/1#x",
+        ] {
+            assert!(validate_admin_uri(uri).is_err(), "{uri}");
+        }
+    }
+
+    #[test]
+    fn test_id_count() {
+        for uri in [
+            "/routes/1/2",
+            "/consumers/jack/credentials/key-1/2",
+            "/consumers/jack/other",
+            "/plugins/list/x",
+            "/plugin_metadata",
+            "/secrets/aws/1",
+            "/foo",
+        ] {
+            assert!(validate_admin_uri(uri).is_err(), "{uri}");
+        }
+    }
+}
@@ -7,16 +7,20 @@ derive_common_default! {
 pub enum PropertyType {
     Boolean,
     List(Box<PropertyType>),
+    Map(Box<PropertyType>),
     Number,
+    Object(Vec<PropertyField>),
     Plugins,
     #[default]
     String,
     JSON,
     Enum(Vec<String>),
+    // Multi-line source code, the value is the language name
+    Code(String),
 }}
 
 derive_common_default! {
-#[derive(TS)]
+#[derive(TS, Hash, Eq, PartialEq)]
 #[ts(export)]
 pub enum Required {
     True,
@@ -24,3 +28,108 @@ pub enum Required {
     False,
     TrueIfOtherMissing(Vec<String>),
 }}
+
+// Child field of a `PropertyType::Object`
+derive_common_default! {
+#[derive(TS, Hash, Eq, PartialEq)]
+#[ts(export)]
+pub struct PropertyField {
+    pub default_value: Option<String>,
+    pub description: String,
+    pub is_required: Required,
+    pub name: String,
+    pub property_type: PropertyType,
+    pub sensitive: bool,
+}}
+
+// APISIX versions where a plugin or field is available, `max` is exclusive
+derive_common_default! {
+#[derive(TS, Hash, Eq, PartialEq)]
+#[ts(export)]
+pub struct VersionRange {
+    pub min: Option<String>,
+    pub max: Option<String>,
+}}
+
+impl VersionRange {
+    pub fn since(min: &str) -> Self {
+        Self {
+            min: Some(min.to_string()),
+            max: None,
+        }
+    }
+
+    // Unknown versions are considered supported, to not hide anything when the version can't be
+    // detected
+    pub fn contains(&self, version: &str) -> bool {
+        let Some(version) = parse_version(version) else {
+            return true;
+        };
+
+        let after_min = self
+            .min
+            .as_deref()
+            .and_then(parse_version)
+            .is_none_or(|min| version >= min);
+        let before_max = self
+            .max
+            .as_deref()
+            .and_then(parse_version)
+            .is_none_or(|max| version < max);
+
+        after_min && before_max
+    }
+}
+
+// Items without a range are available in every version
+pub fn is_version_available(range: &Option<VersionRange>, version: &str) -> bool {
+    range.as_ref().is_none_or(|range| range.contains(version))
+}
+
+// Accepts the format of the `Server` header too, e.g. `APISIX/3.2.1`
+pub fn parse_version(version: &str) -> Option<[u32; 3]> {
+    let version = version.trim();
+    let version = version.strip_prefix("APISIX/").unwrap_or(version);
+    let mut parts = [0; 3];
+
+    for (idx, part) in version.split('.').enumerate() {
+        if idx >= parts.len() {
+            break;
+        }
+
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+        parts[idx] = digits.parse().ok()?;
+    }
+
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("3.2.1"), Some([3, 2, 1]));
+        assert_eq!(parse_version("APISIX/3.9.0"), Some([3, 9, 0]));
+        assert_eq!(parse_version("3.10"), Some([3, 10, 0]));
+        assert_eq!(parse_version("dev"), None);
+    }
+
+    #[test]
+    fn test_version_range() {
+        let range = VersionRange {
+            min: Some("3.0.0".to_string()),
+            max: Some("3.8.0".to_string()),
+        };
+
+        assert!(!range.contains("2.15.3"));
+        assert!(range.contains("3.0.0"));
+        assert!(range.contains("3.7.9"));
+        assert!(!range.contains("3.8.0"));
+        // Unknown versions don't hide anything
+        assert!(range.contains("unknown"));
+        assert!(is_version_available(&None, "2.0.0"));
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    base::{PropertyType, Required},
-    plugins::common::PluginEntities,
+    base::{PropertyType, Required, VersionRange},
+    plugins::{common::PluginEntities, registry::PluginRegistry},
+    schema::ApisixSchema,
+    validation::{validate_fields, validate_plugins, FieldError},
 };
 use crate::{
     macros::{derive_common, derive_common_default},
@@ -144,6 +146,8 @@ pub struct EntityFields {
     pub is_required: Required,
     pub name: String,
     pub property_type: PropertyType,
+    pub sensitive: bool,
+    pub version_range: Option<VersionRange>,
 }}
 
 impl Default for EntityFields {
@@ -157,6 +161,8 @@ impl Default for EntityFields {
             is_required: Default::default(),
             name: Default::default(),
             property_type: Default::default(),
+            sensitive: false,
+            version_range: None,
         }
     }
 }
@@ -188,6 +194,8 @@ pub trait EntityItemTrait {
     const DISPLAY_SHORT: &'static [&'static str] = &[];
     const ID_NAME: &'static str = "id";
     const REQUIRED_VERSION: Option<&'static str> = None;
+    // Key of the entity in the `main` section of the APISIX schema
+    const SCHEMA_KEY: Option<&'static str> = None;
 
     type Value: EntityValueTrait;
 
@@ -216,7 +224,7 @@ impl<A: EntityItemTrait> Entity<A> {
         let id = self.parsed.get_value().get_str(A::ID_NAME);
 
         if id.is_empty() {
-            Err("Id is required for delete".to_string())
+            Err("Id is required".to_string())
         } else {
             Ok(id.to_string())
         }
@@ -229,6 +237,40 @@ impl<A: EntityItemTrait> Entity<A> {
     }
 }
 
+impl<A: EntityItemTrait> Entity<A>
+where
+    Self: EntityTrait,
+{
+    pub fn validate(&self, registry: &PluginRegistry) -> Result<(), Vec<FieldError>> {
+        let values = serde_json::Map::from_iter(self.parsed.get_cloned());
+        let mut errors = vec![];
+
+        validate_fields(&Self::value_fields(), &values, "", &mut errors);
+
+        let plugins = values.get("plugins").and_then(|p| p.as_object());
+
+        if let (Some(entity), Some(plugins)) = (A::PLUGIN_ENTITY, plugins) {
+            validate_plugins(plugins, &entity, registry, "plugins", &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Uses the schema of the running APISIX, the errors use JSON pointers as field names
+    pub fn validate_with_schema(&self, schema: &ApisixSchema) -> Result<(), Vec<FieldError>> {
+        let body = Value::Object(serde_json::Map::from_iter(self.parsed.get_cloned()));
+
+        match A::SCHEMA_KEY {
+            Some(key) => schema.validate_entity(key, A::PLUGIN_ENTITY.as_ref(), &body),
+            None => Ok(()),
+        }
+    }
+}
+
 // Only include macros and traits here
 pub mod prelude {
     macro_rules! entity_trait_get_value {
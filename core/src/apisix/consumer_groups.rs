@@ -19,6 +19,7 @@ impl EntityItemTrait for ConsumerGroup {
     const DISPLAY_SHORT: &'static [&'static str] = &[];
     const DOCS_KEY: &'static str = "consumer-group";
     const PLUGIN_ENTITY: Option<PluginEntities> = Some(PluginEntities::ConsumerGroup);
+    const SCHEMA_KEY: Option<&'static str> = Some("consumer_group");
 
     entity_trait_get_value!();
 }
@@ -3,7 +3,7 @@ use super::{
     plugins::common::PluginEntities,
 };
 use crate::{
-    apisix::base::{PropertyType, Required},
+    apisix::base::{PropertyType, Required, VersionRange},
     macros::derive_common_default,
     proxy::{ProxyFetchMethod, ProxyFetchOpts},
 };
@@ -21,6 +21,7 @@ impl EntityItemTrait for Consumer {
     const DOCS_KEY: &'static str = "consumer";
     const ID_NAME: &'static str = "username";
     const PLUGIN_ENTITY: Option<PluginEntities> = Some(PluginEntities::Consumer);
+    const SCHEMA_KEY: Option<&'static str> = Some("consumer");
 
     entity_trait_get_value!();
 }
@@ -72,6 +73,8 @@ impl EntityTrait for ConsumerEntity {
             EntityFields {
                 description: "Group of the Consumer.".to_string(),
                 name: "group_id".to_string(),
+                // The consumer groups were added in APISIX 3.0
+                version_range: Some(VersionRange::since("3.0.0")),
                 ..EntityFields::default()
             },
         ]
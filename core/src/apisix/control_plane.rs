@@ -1,7 +1,35 @@
+use super::{
+    admin_uri::is_valid_segment, common::prelude::*, routes::Route, services::Service,
+    upstreams::Upstream,
+};
 use crate::{
     macros::derive_common_default,
     proxy::{ProxyFetchMethod, ProxyFetchOpts},
 };
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+
+// Endpoints of the Control API, `*` is an id or a name
+const ENDPOINTS: &[(ProxyFetchMethod, &str)] = &[
+    (ProxyFetchMethod::GET, "/v1/schema"),
+    (ProxyFetchMethod::GET, "/v1/healthcheck"),
+    // The type (`routes`, `services` or `upstreams`) and the id of the checked entity
+    (ProxyFetchMethod::GET, "/v1/healthcheck/*/*"),
+    (ProxyFetchMethod::GET, "/v1/routes"),
+    (ProxyFetchMethod::GET, "/v1/route/*"),
+    (ProxyFetchMethod::GET, "/v1/services"),
+    (ProxyFetchMethod::GET, "/v1/service/*"),
+    (ProxyFetchMethod::GET, "/v1/upstreams"),
+    (ProxyFetchMethod::GET, "/v1/upstream/*"),
+    (ProxyFetchMethod::GET, "/v1/plugin_metadatas"),
+    (ProxyFetchMethod::GET, "/v1/plugin_metadata/*"),
+    // The name of the discovery, like `consul` or `nacos`
+    (ProxyFetchMethod::GET, "/v1/discovery/*/dump"),
+    (ProxyFetchMethod::GET, "/v1/discovery/*/show_dump_file"),
+    (ProxyFetchMethod::POST, "/v1/gc"),
+    (ProxyFetchMethod::PUT, "/v1/plugins/reload"),
+];
 
 derive_common_default! {
 pub struct ControlPlane;}
@@ -13,6 +41,47 @@ impl ControlPlane {
     pub fn get_health_check() -> Result<ProxyFetchOpts, String> {
         ProxyFetchOpts::get("/v1/healthcheck")
     }
+    pub fn get_entity_health_check(src_type: &str, id: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/healthcheck/{src_type}/{id}"))
+    }
+    pub fn get_routes() -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get("/v1/routes")
+    }
+    pub fn get_route(id: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/route/{id}"))
+    }
+    pub fn get_services() -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get("/v1/services")
+    }
+    pub fn get_service(id: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/service/{id}"))
+    }
+    pub fn get_upstreams() -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get("/v1/upstreams")
+    }
+    pub fn get_upstream(id: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/upstream/{id}"))
+    }
+    pub fn get_plugin_metadatas() -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get("/v1/plugin_metadatas")
+    }
+    pub fn get_plugin_metadata(name: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/plugin_metadata/{name}"))
+    }
+    pub fn get_discovery_dump(discovery: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/discovery/{discovery}/dump"))
+    }
+    pub fn get_discovery_dump_file(discovery: &str) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(&format!("/v1/discovery/{discovery}/show_dump_file"))
+    }
+    // Triggers a full garbage collection in the Lua VM of the worker
+    pub fn run_gc() -> Result<ProxyFetchOpts, String> {
+        Ok(ProxyFetchOpts {
+            uri: "/v1/gc".to_string(),
+            method: ProxyFetchMethod::POST,
+            data: None,
+        })
+    }
     pub fn reload_plugins() -> Result<ProxyFetchOpts, String> {
         Ok(ProxyFetchOpts {
             uri: "/v1/plugins/reload".to_string(),
@@ -20,4 +89,233 @@ impl ControlPlane {
             data: None,
         })
     }
+
+    /// Checks that the request is a known endpoint of the Control API before proxying it
+    pub fn validate_opts(opts: &ProxyFetchOpts) -> Result<(), String> {
+        let uri = &opts.uri;
+        let segments: Vec<&str> = uri
+            .strip_prefix('/')
+            .ok_or_else(|| format!("The uri {uri:?} must start with /"))?
+            .split('/')
+            .collect();
+
+        if !segments.iter().all(|s| is_valid_segment(s)) {
+            return Err(format!("The uri {uri:?} has an invalid path"));
+        }
+
+        let methods: Vec<&ProxyFetchMethod> = ENDPOINTS
+            .iter()
+            .filter(|(_, path)| {
+                let pattern: Vec<&str> = path[1..].split('/').collect();
+
+                pattern.len() == segments.len()
+                    && pattern
+                        .iter()
+                        .zip(&segments)
+                        .all(|(p, s)| *p == "*" || p == s)
+            })
+            .map(|(method, _)| method)
+            .collect();
+
+        if methods.is_empty() {
+            return Err(format!(
+                "The uri {uri:?} is not a known endpoint of the Control API"
+            ));
+        }
+
+        if !methods.contains(&&opts.method) {
+            return Err(format!(
+                "The method {:?} is not allowed for {uri:?}",
+                opts.method
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the response against the types of the endpoint, the fields that are not part of
+    /// them are dropped
+    pub fn parse_response(uri: &str, response: Value) -> Result<Value, String> {
+        let segments: Vec<&str> = uri.trim_start_matches('/').split('/').collect();
+
+        match segments.as_slice() {
+            ["v1", "healthcheck"] => typed::<Vec<ControlHealthCheck>>(as_list(response)),
+            ["v1", "healthcheck", _, _] => typed::<ControlHealthCheck>(response),
+            ["v1", "routes" | "services" | "upstreams"] => {
+                typed::<Vec<ControlItem>>(as_list(response))
+            }
+            ["v1", "route" | "service" | "upstream", _] => typed::<ControlItem>(response),
+            _ => Ok(response),
+        }
+    }
+
+    /// Admin API prefix of the entities returned by the uri, used to mask their sensitive values
+    pub fn entity_api_prefix(uri: &str) -> Option<&'static str> {
+        match uri.trim_start_matches('/').split('/').nth(1)? {
+            "route" | "routes" => Some(Route::API_PREFIX),
+            "service" | "services" => Some(Service::API_PREFIX),
+            "upstream" | "upstreams" => Some(Upstream::API_PREFIX),
+            _ => None,
+        }
+    }
+}
+
+// APISIX encodes the empty lists as `{}`
+fn as_list(value: Value) -> Value {
+    match value {
+        Value::Object(map) if map.is_empty() => Value::Array(vec![]),
+        value => value,
+    }
+}
+
+fn typed<T: DeserializeOwned + Serialize>(value: Value) -> Result<Value, String> {
+    let parsed: T = serde_json::from_value(value).map_err(|e| e.to_string())?;
+
+    serde_json::to_value(parsed).map_err(|e| e.to_string())
+}
+
+derive_common_default! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlNodeStatus {
+    #[default]
+    Healthy,
+    MostlyHealthy,
+    MostlyUnhealthy,
+    Unhealthy,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct ControlHealthCheckCounter {
+    pub http_failure: u32,
+    pub success: u32,
+    pub tcp_failure: u32,
+    pub timeout_failure: u32,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct ControlHealthCheckNode {
+    pub counter: ControlHealthCheckCounter,
+    pub hostname: Option<String>,
+    pub ip: String,
+    pub port: u16,
+    pub status: ControlNodeStatus,
+}}
+
+// Items of `/v1/healthcheck`, and the response of `/v1/healthcheck/{type}/{id}`
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct ControlHealthCheck {
+    // Key of the checked entity, like `/apisix/routes/1`
+    pub name: String,
+    pub nodes: Vec<ControlHealthCheckNode>,
+    #[serde(rename = "type")]
+    pub check_type: String,
+}}
+
+// Items of `/v1/routes`, `/v1/services` and `/v1/upstreams`, with the entities loaded by APISIX
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlItem {
+    #[ts(type = "number | null")]
+    pub created_index: Option<u64>,
+    pub key: String,
+    #[ts(type = "number | null")]
+    pub modified_index: Option<u64>,
+    #[ts(type = "unknown")]
+    pub value: Value,
+}}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn opts(method: ProxyFetchMethod, uri: &str) -> ProxyFetchOpts {
+        ProxyFetchOpts {
+            uri: uri.to_string(),
+            method,
+            data: None,
+        }
+    }
+
+    #[test]
+    fn test_known_endpoints() {
+        for uri in [
+            "/v1/schema",
+            "/v1/healthcheck",
+            "/v1/healthcheck/upstreams/1",
+            "/v1/route/1",
+            "/v1/plugin_metadata/file-logger",
+            "/v1/discovery/consul/dump",
+        ] {
+            assert_eq!(
+                ControlPlane::validate_opts(&opts(ProxyFetchMethod::GET, uri)),
+                Ok(())
+            );
+        }
+
+        assert!(ControlPlane::validate_opts(&ControlPlane::run_gc().unwrap()).is_ok());
+        assert!(ControlPlane::validate_opts(&ControlPlane::reload_plugins().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_endpoints() {
+        for (method, uri) in [
+            (ProxyFetchMethod::GET, "/v1/gc"),
+            (ProxyFetchMethod::DELETE, "/v1/route/1"),
+            (ProxyFetchMethod::GET, "/v1/route"),
+            (ProxyFetchMethod::GET, "/v1/route/1/2"),
+            (ProxyFetchMethod::GET, "/v1/route/.."),
+            (ProxyFetchMethod::GET, "/v1/../apisix/admin/routes"),
+            (ProxyFetchMethod::GET, "/v1/routes?format=html"),
+            (ProxyFetchMethod::GET, "v1/routes"),
+        ] {
+            assert!(
+                ControlPlane::validate_opts(&opts(method, uri)).is_err(),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_typed_responses() {
+        let health_check = json!([{
+            "name": "/apisix/routes/1",
+            "nodes": [{
+                "counter": { "http_failure": 0, "success": 2, "tcp_failure": 0, "timeout_failure": 0 },
+                "ip": "127.0.0.1",
+                "port": 80,
+                "status": "healthy",
+            }],
+            "type": "http",
+        }]);
+        assert!(ControlPlane::parse_response("/v1/healthcheck", health_check).is_ok());
+
+        let routes = json!([{
+            "clean_handlers": {},
+            "createdIndex": 3,
+            "key": "/apisix/routes/1",
+            "modifiedIndex": 4,
+            "value": { "id": "1", "uri": "/x" },
+        }]);
+        let parsed = ControlPlane::parse_response("/v1/routes", routes).unwrap();
+        assert_eq!(parsed[0]["value"]["uri"], "/x");
+        assert!(parsed[0].get("clean_handlers").is_none());
+
+        assert_eq!(
+            ControlPlane::parse_response("/v1/upstreams", json!({})),
+            Ok(json!([]))
+        );
+        assert!(ControlPlane::parse_response("/v1/route/1", json!({ "value": {} })).is_err());
+        assert!(ControlPlane::parse_response("/v1/healthcheck", json!([{ "name": 1 }])).is_err());
+    }
 }
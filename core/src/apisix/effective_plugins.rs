@@ -0,0 +1,166 @@
+use super::{plugins::meta::PluginMeta, schema::ApisixSchema};
+use crate::{
+    macros::{derive_common, derive_common_default},
+    proxy::ProxyFetchOpts,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use ts_rs::TS;
+
+derive_common! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+pub enum PluginSource {
+    Service,
+    PluginConfig,
+    Route,
+    ConsumerGroup,
+    Consumer,
+}}
+
+derive_common! {
+#[derive(TS)]
+#[ts(export)]
+pub struct EffectivePlugin {
+    #[ts(type = "unknown")]
+    pub config: Value,
+    // Disabled with `_meta.disable`, the plugin is configured but not executed
+    pub disabled: bool,
+    pub name: String,
+    // Sources that also set the plugin, but whose config was replaced
+    pub overridden: Vec<PluginSource>,
+    #[ts(type = "number | null")]
+    pub priority: Option<i64>,
+    pub source: PluginSource,
+}}
+
+// Items of the admin API list responses, either the whole item or only its `value`
+derive_common_default! {
+pub struct PluginSources {
+    pub consumer_groups: Vec<Value>,
+    pub consumers: Vec<Value>,
+    pub plugin_configs: Vec<Value>,
+    pub routes: Vec<Value>,
+    pub services: Vec<Value>,
+}}
+
+pub fn get_plugin_configs() -> Result<ProxyFetchOpts, String> {
+    ProxyFetchOpts::get("/plugin_configs")
+}
+
+// Ids can be numbers or strings in APISIX
+fn id_field(value: &Value, key: &str) -> Option<String> {
+    match value.get(key)? {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn find_item<'a>(items: &'a [Value], key: &str, id: &str) -> Option<&'a Value> {
+    items
+        .iter()
+        .map(|item| item.get("value").unwrap_or(item))
+        .find(|item| id_field(item, key).as_deref() == Some(id))
+}
+
+fn find_required<'a>(
+    items: &'a [Value],
+    key: &str,
+    id: &str,
+    name: &str,
+) -> Result<&'a Value, String> {
+    find_item(items, key, id).ok_or_else(|| format!("{} {} not found", name, id))
+}
+
+/// Resolves the plugins that run for a route, optionally for a consumer authenticated in it.
+/// Like APISIX, the precedence from lowest to highest is: service, plugin config, route, consumer
+/// group and consumer. The result is sorted by execution order, using the priorities of the schema
+/// unless `_meta.priority` overrides them.
+pub fn effective_plugins(
+    route_id: &str,
+    consumer: Option<&str>,
+    sources: &PluginSources,
+    schema: Option<&ApisixSchema>,
+) -> Result<Vec<EffectivePlugin>, String> {
+    let route = find_required(&sources.routes, "id", route_id, "Route")?;
+    let mut layers = vec![];
+
+    if let Some(service_id) = id_field(route, "service_id") {
+        let service = find_required(&sources.services, "id", &service_id, "Service")?;
+        layers.push((PluginSource::Service, service));
+    }
+
+    if let Some(plugin_config_id) = id_field(route, "plugin_config_id") {
+        let plugin_config = find_required(
+            &sources.plugin_configs,
+            "id",
+            &plugin_config_id,
+            "Plugin config",
+        )?;
+        layers.push((PluginSource::PluginConfig, plugin_config));
+    }
+
+    layers.push((PluginSource::Route, route));
+
+    if let Some(username) = consumer {
+        let consumer = find_required(&sources.consumers, "username", username, "Consumer")?;
+
+        if let Some(group_id) = id_field(consumer, "group_id") {
+            let group = find_required(&sources.consumer_groups, "id", &group_id, "Consumer group")?;
+            layers.push((PluginSource::ConsumerGroup, group));
+        }
+
+        layers.push((PluginSource::Consumer, consumer));
+    }
+
+    let plugin_schemas = schema.map(|s| &s.plugins);
+    let mut merged: HashMap<String, EffectivePlugin> = HashMap::new();
+
+    for (source, value) in layers {
+        let Some(plugins) = value.get("plugins").and_then(Value::as_object) else {
+            continue;
+        };
+
+        for (name, config) in plugins.iter() {
+            let plugin_schema = plugin_schemas.and_then(|p| p.get(name));
+
+            // The auth plugins of a consumer hold its credentials, they don't run as route plugins
+            let plugin_type = plugin_schema.and_then(|s| s.plugin_type.as_deref());
+            if source == PluginSource::Consumer && plugin_type == Some("auth") {
+                continue;
+            }
+
+            let plugin = merged
+                .entry(name.clone())
+                .or_insert_with(|| EffectivePlugin {
+                    config: Value::Object(Map::new()),
+                    disabled: false,
+                    name: name.clone(),
+                    overridden: vec![],
+                    priority: plugin_schema.and_then(|s| s.priority),
+                    source: source.clone(),
+                });
+
+            if plugin.source != source {
+                plugin.overridden.push(plugin.source.clone());
+                plugin.source = source.clone();
+            }
+
+            let meta = PluginMeta::from_config(config).unwrap_or_default();
+
+            plugin.config = config.clone();
+            plugin.disabled = meta.disable.unwrap_or(false);
+            plugin.priority = meta
+                .priority
+                .or_else(|| plugin_schema.and_then(|s| s.priority));
+        }
+    }
+
+    let mut plugins: Vec<EffectivePlugin> = merged.into_values().collect();
+
+    // Higher priorities run first, the plugins without a known priority are listed last
+    plugins.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.name.cmp(&b.name)));
+
+    Ok(plugins)
+}
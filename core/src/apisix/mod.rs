@@ -1,11 +1,17 @@
+pub mod admin_uri;
 pub mod base;
 pub mod common;
 pub mod consumer_groups;
 pub mod consumers;
 pub mod control_plane;
+pub mod effective_plugins;
 pub mod plugins;
+pub mod promotion;
+pub mod redaction;
 pub mod routes;
+pub mod schema;
 pub mod secrets;
 pub mod services;
 pub mod stream_routes;
 pub mod upstreams;
+pub mod validation;
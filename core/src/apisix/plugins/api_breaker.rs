@@ -0,0 +1,101 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ApiBreakerPlugin(pub PluginDefinition);}
+
+impl ApiBreakerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "api-breaker".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "HTTP error code to return when Upstream is unhealthy.".to_string(),
+                    is_required: Required::True,
+                    name: "break_response_code".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Body of the response message to return when Upstream is unhealthy.".to_string(),
+                    name: "break_response_body".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Headers of the response message to return when Upstream is unhealthy.".to_string(),
+                    name: "break_response_headers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::Object(vec![
+                        PropertyField {
+                            is_required: Required::True,
+                            name: "key".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            is_required: Required::True,
+                            name: "value".to_string(),
+                            ..Default::default()
+                        },
+                    ]))),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("300".to_string()),
+                    description: "Maximum time in seconds the Upstream is considered unhealthy.".to_string(),
+                    name: "max_breaker_sec".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Status codes and number of failures after which the Upstream is considered unhealthy.".to_string(),
+                    name: "unhealthy".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some("[500]".to_string()),
+                            description: "Status codes of the Upstream to be considered unhealthy.".to_string(),
+                            name: "http_statuses".to_string(),
+                            property_type: PropertyType::List(Box::new(PropertyType::Number)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("3".to_string()),
+                            description: "Number of consecutive failures for the Upstream to be considered unhealthy.".to_string(),
+                            name: "failures".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Status codes and number of successes after which the Upstream is considered healthy again.".to_string(),
+                    name: "healthy".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some("[200]".to_string()),
+                            description: "Status codes of the Upstream to be considered healthy.".to_string(),
+                            name: "http_statuses".to_string(),
+                            property_type: PropertyType::List(Box::new(PropertyType::Number)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("3".to_string()),
+                            description: "Number of consecutive healthy requests for the Upstream to be considered healthy.".to_string(),
+                            name: "successes".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
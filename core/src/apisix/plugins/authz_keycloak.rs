@@ -0,0 +1,137 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct AuthzKeycloakPlugin(pub PluginDefinition);}
+
+impl AuthzKeycloakPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "authz-keycloak".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "The identifier of the resource server to which the client is seeking access.".to_string(),
+                    is_required: Required::True,
+                    name: "client_id".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "The client secret, if required. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
+                    is_secret: true,
+                    name: "client_secret".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Discovery document URL of the Keycloak authorization service.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["token_endpoint".to_string()]),
+                    name: "discovery".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Token endpoint URL of the Keycloak authorization service. Takes precedence over the discovery document.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["discovery".to_string()]),
+                    name: "token_endpoint".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URL of the UMA-compliant resource registration endpoint. Takes precedence over the discovery document.".to_string(),
+                    name: "resource_registration_endpoint".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("urn:ietf:params:oauth:grant-type:uma-ticket".to_string()),
+                    description: "Grant type used to obtain the authorization decision.".to_string(),
+                    name: "grant_type".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "urn:ietf:params:oauth:grant-type:uma-ticket".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("ENFORCING".to_string()),
+                    description: "Single policy enforcement mode.".to_string(),
+                    name: "policy_enforcement_mode".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "ENFORCING".to_string(),
+                        "PERMISSIVE".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Static permission to request, an array of strings each representing a resource and optionally one or more scopes.".to_string(),
+                    name: "permissions".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, dynamically resolves the request URI to resources using the resource registration endpoint instead of the static permission.".to_string(),
+                    name: "lazy_load_paths".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, maps the HTTP request type to scope of the same name and adds to all requested permissions.".to_string(),
+                    name: "http_method_as_scope".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3000".to_string()),
+                    description: "Timeout in ms for the HTTP connection with the Identity Server.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, verifies if TLS certificate matches hostname.".to_string(),
+                    name: "ssl_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("86400".to_string()),
+                    description: "The default cache time in seconds for the discovery document and the token.".to_string(),
+                    name: "cache_ttl_seconds".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "A URL to redirect the user to instead of returning an error like 403 Forbidden.".to_string(),
+                    name: "access_denied_redirect_uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("300".to_string()),
+                    description: "Expiration time(s) of the access token.".to_string(),
+                    name: "access_token_expires_in".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3600".to_string()),
+                    description: "Expiration time(s) of the refresh token.".to_string(),
+                    name: "refresh_token_expires_in".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Set this to generate a token using a password grant type from the given URI.".to_string(),
+                    name: "password_grant_token_generation_incoming_uri".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 
 derive_common_default! {
-pub struct ConsumerBasicAuthPlugin(PluginDefinition);}
+pub struct ConsumerBasicAuthPlugin(pub PluginDefinition);}
 
 impl ConsumerBasicAuthPlugin {
     pub fn new() -> Self {
@@ -20,23 +20,30 @@ impl ConsumerBasicAuthPlugin {
                     default_value: None,
                     description: "Unique username for a Consumer. If multiple Consumers use the same username, a request matching exception is raised.".to_string(),
                     is_required: Required::True,
+                    is_secret: false,
                     name: "username".to_string(),
                     property_type: PropertyType::String,
+                    sensitive: false,
+                    version_range: None,
                 },
                 PluginOption {
                     default_value: None,
                     description: "Password of the user. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
                     is_required: Required::True,
+                    is_secret: true,
                     name: "password".to_string(),
                     property_type: PropertyType::String,
+                    sensitive: true,
+                    version_range: None,
                 },
             ],
+            ..Default::default()
         })
     }
 }
 
 derive_common_default! {
-pub struct RouteBasicAuthPlugin(PluginDefinition);}
+pub struct RouteBasicAuthPlugin(pub PluginDefinition);}
 
 impl RouteBasicAuthPlugin {
     pub fn new() -> Self {
@@ -52,9 +59,13 @@ impl RouteBasicAuthPlugin {
                     "Set to true will not pass the authorization request headers to the Upstream."
                         .to_string(),
                 is_required: Required::False,
+                is_secret: false,
                 name: "hide_credentials".to_string(),
                 property_type: PropertyType::Boolean,
+                sensitive: false,
+                version_range: None,
             }],
+            ..Default::default()
         })
     }
 }
@@ -0,0 +1,68 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct BodyTransformerPlugin(pub PluginDefinition);}
+
+fn transform_fields() -> Vec<PropertyField> {
+    vec![
+        PropertyField {
+            description: "Format of the body before the transformation. If not set, it is guessed from the Content-Type header.".to_string(),
+            name: "input_format".to_string(),
+            property_type: PropertyType::Enum(vec![
+                "xml".to_string(),
+                "json".to_string(),
+                "encoded".to_string(),
+                "args".to_string(),
+                "plain".to_string(),
+                "multipart".to_string(),
+            ]),
+            ..Default::default()
+        },
+        PropertyField {
+            description: "lua-resty-template template used to render the new body.".to_string(),
+            is_required: Required::True,
+            name: "template".to_string(),
+            ..Default::default()
+        },
+        PropertyField {
+            default_value: Some("false".to_string()),
+            description: "When set to true, the template is decoded from base64.".to_string(),
+            name: "template_is_base64".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+    ]
+}
+
+impl BodyTransformerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "body-transformer".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Transformation of the request body.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["response".to_string()]),
+                    name: "request".to_string(),
+                    property_type: PropertyType::Object(transform_fields()),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Transformation of the response body.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["request".to_string()]),
+                    name: "response".to_string(),
+                    property_type: PropertyType::Object(transform_fields()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{apisix::base::PropertyType, macros::derive_common_default};
+
+derive_common_default! {
+pub struct BrotliPlugin(pub PluginDefinition);}
+
+impl BrotliPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "brotli".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some(r#"["text/html"]"#.to_string()),
+                    description: "Dynamically sets the brotli_types directive. Use \"*\" to match all MIME types.".to_string(),
+                    name: "types".to_string(),
+                    property_type: PropertyType::JSON,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("20".to_string()),
+                    description: "Dynamically sets the brotli_min_length directive.".to_string(),
+                    name: "min_length".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("6".to_string()),
+                    description: "Dynamically sets the brotli_comp_level directive, between 0 and 11.".to_string(),
+                    name: "comp_level".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("0".to_string()),
+                    description: "Compression mode: 0 is generic, 1 is text (for UTF-8 format text input) and 2 is font (for WOFF 2.0).".to_string(),
+                    name: "mode".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("19".to_string()),
+                    description: "Dynamically sets the brotli sliding window size, between 10 and 24.".to_string(),
+                    name: "lgwin".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("0".to_string()),
+                    description: "Dynamically sets the brotli input block size, 0 or between 16 and 24.".to_string(),
+                    name: "lgblock".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1.1".to_string()),
+                    description: "Dynamically sets the brotli_http_version directive.".to_string(),
+                    name: "http_version".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "Dynamically sets the vary directive.".to_string(),
+                    name: "vary".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, VersionRange},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ChaitinWafPlugin(pub PluginDefinition);}
+
+impl ChaitinWafPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "chaitin-waf".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("block".to_string()),
+                    description: "Determines how the plugin behaves for matched requests. In monitor mode the requests are only logged.".to_string(),
+                    name: "mode".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "off".to_string(),
+                        "monitor".to_string(),
+                        "block".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "List of matching rules, the plugin is only executed for the requests that match. Each rule has a list of vars with the same format as the vars of a Route.".to_string(),
+                    name: "match".to_string(),
+                    property_type: PropertyType::JSON,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, adds the X-APISIX-CHAITIN-WAF headers to the response.".to_string(),
+                    name: "append_waf_resp_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, adds the debugging headers to the response. Only used when append_waf_resp_header is true.".to_string(),
+                    name: "append_waf_debug_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Connection settings to the SafeLine WAF. Overrides the ones in the plugin metadata, where the nodes are configured.".to_string(),
+                    name: "config".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some("1000".to_string()),
+                            description: "Connect timeout in milliseconds.".to_string(),
+                            name: "connect_timeout".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("1000".to_string()),
+                            description: "Send timeout in milliseconds.".to_string(),
+                            name: "send_timeout".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("1000".to_string()),
+                            description: "Read timeout in milliseconds.".to_string(),
+                            name: "read_timeout".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("1024".to_string()),
+                            description: "Maximum size of the request body in KB that is sent to the WAF.".to_string(),
+                            name: "req_body_size".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("256".to_string()),
+                            description: "Maximum number of idle connections in the keepalive pool.".to_string(),
+                            name: "keepalive_size".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("60000".to_string()),
+                            description: "Idle timeout of the keepalive connections in milliseconds.".to_string(),
+                            name: "keepalive_timeout".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("true".to_string()),
+                            description: "When set to true, sends the real client IP from the real-ip plugin instead of the remote address.".to_string(),
+                            name: "real_client_ip".to_string(),
+                            property_type: PropertyType::Boolean,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ],
+            version_range: Some(VersionRange::since("3.6.0")),
+        })
+    }
+}
@@ -1,7 +1,14 @@
+use super::meta::{meta_option, META_KEY};
 use crate::{
-    apisix::base::{PropertyType, Required},
+    apisix::{
+        base::{PropertyType, Required, VersionRange},
+        validation::{
+            join_path, plugin_config_issues, validate_fields, validate_value, FieldError,
+        },
+    },
     macros::derive_common_default,
 };
+use serde_json::Value;
 use std::collections::HashSet;
 use ts_rs::TS;
 
@@ -24,8 +31,13 @@ pub struct PluginOption {
     pub default_value: Option<String>,
     pub description: String,
     pub is_required: Required,
+    // Can be referenced from a `Secret` with `$secret://...` instead of stored inline
+    pub is_secret: bool,
     pub name: String,
     pub property_type: PropertyType,
+    // Masked in the responses of the server, like passwords and keys
+    pub sensitive: bool,
+    pub version_range: Option<VersionRange>,
 }}
 
 derive_common_default! {
@@ -35,4 +47,99 @@ pub struct PluginDefinition {
     pub entities: HashSet<PluginEntities>,
     pub name: String,
     pub options: Vec<PluginOption>,
+    pub version_range: Option<VersionRange>,
 }}
+
+impl PluginDefinition {
+    pub fn validate(&self, config: &Value, path: &str, errors: &mut Vec<FieldError>) {
+        match config.as_object() {
+            Some(config) => validate_fields(&self.options, config, path, errors),
+            None => errors.push(FieldError::new(path, "must be an object")),
+        }
+
+        if let Some(meta) = config.get(META_KEY) {
+            let meta_path = join_path(path, META_KEY);
+            validate_value(&meta_option().property_type, meta, &meta_path, errors);
+        }
+
+        for issue in plugin_config_issues(&self.name, config) {
+            errors.push(issue.to_field_error(path));
+        }
+    }
+}
+
+// Options of the batch processor used by the logger plugins
+pub fn batch_processor_options(default_name: &str) -> Vec<PluginOption> {
+    vec![
+        PluginOption {
+            default_value: Some(default_name.to_string()),
+            description: "Unique identifier for the batch processor.".to_string(),
+            name: "name".to_string(),
+            ..Default::default()
+        },
+        PluginOption {
+            default_value: Some("1000".to_string()),
+            description: "Maximum number of log entries allowed in one batch. Once reached, the batch will be sent to the logging service.".to_string(),
+            name: "batch_max_size".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            default_value: Some("5".to_string()),
+            description: "Maximum age in seconds of the batch without new entries before it is sent.".to_string(),
+            name: "inactive_timeout".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            default_value: Some("60".to_string()),
+            description: "Maximum age in seconds of the oldest entry in a batch before the batch must be sent.".to_string(),
+            name: "buffer_duration".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            default_value: Some("0".to_string()),
+            description: "Maximum number of retries before removing the entry from the processing pipeline when an error occurs.".to_string(),
+            name: "max_retry_count".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            default_value: Some("1".to_string()),
+            description: "Number of seconds the process execution should be delayed if the execution fails.".to_string(),
+            name: "retry_delay".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+    ]
+}
+
+pub fn log_format_option() -> PluginOption {
+    PluginOption {
+        description: "Log format declared as key value pairs. Values can reference APISIX or Nginx variables by prefixing them with $. When not set, the format from the plugin metadata or the default format is used.".to_string(),
+        name: "log_format".to_string(),
+        property_type: PropertyType::Map(Box::new(PropertyType::String)),
+        ..Default::default()
+    }
+}
+
+pub fn include_req_body_option() -> PluginOption {
+    PluginOption {
+        default_value: Some("false".to_string()),
+        description: "When set to true includes the request body in the log.".to_string(),
+        name: "include_req_body".to_string(),
+        property_type: PropertyType::Boolean,
+        ..Default::default()
+    }
+}
+
+pub fn include_resp_body_option() -> PluginOption {
+    PluginOption {
+        default_value: Some("false".to_string()),
+        description: "When set to true includes the response body in the log.".to_string(),
+        name: "include_resp_body".to_string(),
+        property_type: PropertyType::Boolean,
+        ..Default::default()
+    }
+}
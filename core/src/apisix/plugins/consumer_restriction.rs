@@ -1,8 +1,11 @@
 use super::common::{PluginDefinition, PluginEntities, PluginOption};
-use crate::{apisix::base::PropertyType, macros::derive_common_default};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
 
 derive_common_default! {
-pub struct ConsumerRestrictionPlugin(PluginDefinition);}
+pub struct ConsumerRestrictionPlugin(pub PluginDefinition);}
 
 impl ConsumerRestrictionPlugin {
     pub fn new() -> Self {
@@ -61,11 +64,34 @@ impl ConsumerRestrictionPlugin {
                         .to_string(),
                     name: "allowed_by_methods".to_string(),
                     property_type: PropertyType::List(Box::new(
-                            PropertyType::JSON
+                            PropertyType::Object(vec![
+                                PropertyField {
+                                    description: "Username of the Consumer.".to_string(),
+                                    is_required: Required::True,
+                                    name: "user".to_string(),
+                                    ..Default::default()
+                                },
+                                PropertyField {
+                                    description: "HTTP methods allowed for the Consumer.".to_string(),
+                                    is_required: Required::True,
+                                    name: "methods".to_string(),
+                                    property_type: PropertyType::List(Box::new(PropertyType::Enum(
+                                        [
+                                            "GET", "POST", "PUT", "DELETE", "PATCH", "HEAD",
+                                            "OPTIONS", "CONNECT", "TRACE", "PURGE",
+                                        ]
+                                        .iter()
+                                        .map(|m| m.to_string())
+                                        .collect(),
+                                    ))),
+                                    ..Default::default()
+                                },
+                            ])
                     )),
                     ..Default::default()
                 },
             ],
+            ..Default::default()
         })
     }
 }
@@ -2,7 +2,7 @@ use super::common::{PluginDefinition, PluginEntities, PluginOption};
 use crate::{apisix::base::PropertyType, macros::derive_common_default};
 
 derive_common_default! {
-pub struct CorsPlugin(PluginDefinition);}
+pub struct CorsPlugin(pub PluginDefinition);}
 
 impl CorsPlugin {
     pub fn new() -> Self {
@@ -11,16 +11,19 @@ impl CorsPlugin {
             entities: [PluginEntities::Route, PluginEntities::Consumer, PluginEntities::ConsumerGroup, PluginEntities::Service].iter().cloned().collect(),
             options: vec![
                 PluginOption {
+                    default_value: Some("*".to_string()),
                     description: "Origins to allow CORS. Use the scheme://host:port format. For example, https://somedomain.com:8081. If you have multiple origins, use a , to list them. If allow_credential is set to false, you can enable CORS for all origins by using *. If allow_credential is set to true, you can forcefully allow CORS on all origins by using ** but it will pose some security issues.".to_string(),
                     name: "allow_origins".to_string(),
                     ..Default::default()
                 },
                 PluginOption {
+                    default_value: Some("*".to_string()),
                     description: "Request methods to enable CORS on. For example GET, POST. Use , to add multiple methods. If allow_credential is set to false, you can enable CORS for all methods by using *. If allow_credential is set to true, you can forcefully allow CORS on all methods by using ** but it will pose some security issues.".to_string(),
                     name: "allow_methods".to_string(),
                     ..Default::default()
                 },
                 PluginOption {
+                    default_value: Some("*".to_string()),
                     description: "Headers in the request allowed when accessing a cross-origin resource. Use , to add multiple headers. If allow_credential is set to false, you can enable CORS for all request headers by using *. If allow_credential is set to true, you can forcefully allow CORS on all request headers by using ** but it will pose some security issues.".to_string(),
                     name: "allow_headers".to_string(),
                     ..Default::default()
@@ -31,13 +34,15 @@ impl CorsPlugin {
                     ..Default::default()
                 },
                 PluginOption {
-                    description: "False	5	Maximum time in seconds the result is cached. If the time is within this limit, the browser will check the cached result. Set to -1 to disable caching. Note that the maximum value is browser dependent. See Access-Control-Max-Age for more details.".to_string(),
+                    default_value: Some("5".to_string()),
+                    description: "Maximum time in seconds the result is cached. If the time is within this limit, the browser will check the cached result. Set to -1 to disable caching. Note that the maximum value is browser dependent. See Access-Control-Max-Age for more details.".to_string(),
                     name: "max_age".to_string(),
                     property_type: PropertyType::Number,
                     ..Default::default()
                 },
                 PluginOption {
-                    description: "false	When set to true, allows requests to include credentials like cookies. According to CORS specification, if you set this to true, you cannot use '*' to allow all for the other attributes.".to_string(),
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, allows requests to include credentials like cookies. According to CORS specification, if you set this to true, you cannot use '*' to allow all for the other attributes.".to_string(),
                     name: "allow_credential".to_string(),
                     property_type: PropertyType::Boolean,
                     ..Default::default()
@@ -59,6 +64,7 @@ impl CorsPlugin {
                     ..Default::default()
                 }
             ],
+            ..Default::default()
         })
     }
 }
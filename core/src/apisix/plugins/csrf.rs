@@ -0,0 +1,44 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct CsrfPlugin(pub PluginDefinition);}
+
+impl CsrfPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "csrf".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Secret key used to encrypt the cookie. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
+                    is_required: Required::True,
+                    is_secret: true,
+                    name: "key".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("7200".to_string()),
+                    description: "Expiration time in seconds of the CSRF cookie. Set to 0 to skip checking expiration time.".to_string(),
+                    name: "expires".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("apisix-csrf-token".to_string()),
+                    description: "Name of the token in the generated cookie.".to_string(),
+                    name: "name".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct DegraphqlPlugin(pub PluginDefinition);}
+
+impl DegraphqlPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "degraphql".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "The GraphQL query sent to the Upstream.".to_string(),
+                    is_required: Required::True,
+                    name: "query".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "The name of the operation, only required when multiple operations exist in the query.".to_string(),
+                    name: "operation_name".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "The variables used in the GraphQL query, taken from the request arguments or body.".to_string(),
+                    name: "variables".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
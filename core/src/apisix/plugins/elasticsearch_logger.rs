@@ -0,0 +1,92 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, include_resp_body_option, log_format_option,
+    PluginDefinition, PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ElasticsearchLoggerPlugin(pub PluginDefinition);}
+
+impl ElasticsearchLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "elasticsearch-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Elasticsearch API endpoints. If multiple endpoints are configured, they will be written randomly.".to_string(),
+                    is_required: Required::True,
+                    name: "endpoint_addrs".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Elasticsearch field configuration.".to_string(),
+                    is_required: Required::True,
+                    name: "field".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "Elasticsearch _index field.".to_string(),
+                            is_required: Required::True,
+                            name: "index".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Elasticsearch _type field, only used by Elasticsearch versions before 7.".to_string(),
+                            name: "type".to_string(),
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                log_format_option(),
+                PluginOption {
+                    description: "Elasticsearch authentication config.".to_string(),
+                    name: "auth".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "Elasticsearch authentication username.".to_string(),
+                            is_required: Required::True,
+                            name: "username".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Elasticsearch authentication password.".to_string(),
+                            is_required: Required::True,
+                            name: "password".to_string(),
+                            sensitive: true,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true enables SSL verification.".to_string(),
+                    name: "ssl_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("10".to_string()),
+                    description: "Elasticsearch send data timeout in seconds.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                include_req_body_option(),
+                include_resp_body_option(),
+            ]
+            .into_iter()
+            .chain(batch_processor_options("elasticsearch-logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use super::{
+    common::{PluginDefinition, PluginEntities},
+    ext_plugin_pre_req::ext_plugin_options,
+};
+use crate::macros::derive_common_default;
+
+derive_common_default! {
+pub struct ExtPluginPostRespPlugin(pub PluginDefinition);}
+
+impl ExtPluginPostRespPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "ext-plugin-post-resp".to_string(),
+            entities: [
+                PluginEntities::Route,
+                PluginEntities::Consumer,
+                PluginEntities::ConsumerGroup,
+                PluginEntities::Service,
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+            options: ext_plugin_options(),
+            ..Default::default()
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use super::common::{PluginDefinition, PluginEntities, PluginOption};
-use crate::{apisix::base::PropertyType, macros::derive_common_default};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
 
 derive_common_default! {
-pub struct ExtPluginPreReqPlugin(PluginDefinition);}
+pub struct ExtPluginPreReqPlugin(pub PluginDefinition);}
 
 impl ExtPluginPreReqPlugin {
     pub fn new() -> Self {
@@ -17,22 +20,41 @@ impl ExtPluginPreReqPlugin {
             .iter()
             .cloned()
             .collect(),
-            options: vec![
-                PluginOption {
-                    name: "conf".to_string(),
-                    property_type: PropertyType::List(
-                        Box::new(PropertyType::JSON)
-                    ),
-                    description: "List of Plugins and their configurations to be executed on the Plugin Runner.".to_string(),
-                    ..Default::default()
-                },
-                PluginOption {
-                    name: "allow_degradation".to_string(),
-                    property_type: PropertyType::Boolean,
-                    description: "Sets Plugin degradation when the Plugin Runner is not available. When set to true, requests are allowed to continue.".to_string(),
-                    ..Default::default()
-                }
-            ],
+            options: ext_plugin_options(),
+            ..Default::default()
         })
     }
 }
+
+// Shared by the ext-plugin-pre-req, ext-plugin-post-req and ext-plugin-post-resp plugins
+pub fn ext_plugin_options() -> Vec<PluginOption> {
+    vec![
+        PluginOption {
+            name: "conf".to_string(),
+            property_type: PropertyType::List(
+                Box::new(PropertyType::Object(vec![
+                    PropertyField {
+                        description: "Name of the Plugin in the Plugin Runner.".to_string(),
+                        is_required: Required::True,
+                        name: "name".to_string(),
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Configuration of the Plugin, usually as a JSON string.".to_string(),
+                        is_required: Required::True,
+                        name: "value".to_string(),
+                        ..Default::default()
+                    },
+                ]))
+            ),
+            description: "List of Plugins and their configurations to be executed on the Plugin Runner.".to_string(),
+            ..Default::default()
+        },
+        PluginOption {
+            name: "allow_degradation".to_string(),
+            property_type: PropertyType::Boolean,
+            description: "Sets Plugin degradation when the Plugin Runner is not available. When set to true, requests are allowed to continue.".to_string(),
+            ..Default::default()
+        }
+    ]
+}
@@ -0,0 +1,88 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct FaultInjectionPlugin(pub PluginDefinition);}
+
+impl FaultInjectionPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "fault-injection".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Returns the response directly to the client without forwarding it to the Upstream.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["delay".to_string()]),
+                    name: "abort".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "HTTP status code of the response returned to the client.".to_string(),
+                            is_required: Required::True,
+                            name: "http_status".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Body of the response returned to the client. Nginx variables like client addr: $remote_addr can be used.".to_string(),
+                            name: "body".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Headers of the response returned to the client.".to_string(),
+                            name: "headers".to_string(),
+                            property_type: PropertyType::Map(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Percentage of requests to be aborted.".to_string(),
+                            name: "percentage".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Rules which are matched before executing the fault injection, with the same format as the vars of a Route.".to_string(),
+                            name: "vars".to_string(),
+                            property_type: PropertyType::JSON,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Delays the request before forwarding it to the Upstream.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["abort".to_string()]),
+                    name: "delay".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "Duration of the delay in seconds. Can be decimal.".to_string(),
+                            is_required: Required::True,
+                            name: "duration".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Percentage of requests to be delayed.".to_string(),
+                            name: "percentage".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Rules which are matched before executing the fault injection, with the same format as the vars of a Route.".to_string(),
+                            name: "vars".to_string(),
+                            property_type: PropertyType::JSON,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use super::common::{
+    include_req_body_option, include_resp_body_option, log_format_option, PluginDefinition,
+    PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct FileLoggerPlugin(pub PluginDefinition);}
+
+impl FileLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "file-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Log file path.".to_string(),
+                    is_required: Required::True,
+                    name: "path".to_string(),
+                    ..Default::default()
+                },
+                log_format_option(),
+                include_req_body_option(),
+                include_resp_body_option(),
+                PluginOption {
+                    description: "Logs will be recorded only when the rules match, with the same format as the vars of a Route.".to_string(),
+                    name: "match".to_string(),
+                    property_type: PropertyType::JSON,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ForwardAuthPlugin(pub PluginDefinition);}
+
+impl ForwardAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "forward-auth".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "URI of the authorization service.".to_string(),
+                    is_required: Required::True,
+                    name: "uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, verifies the SSL certificate.".to_string(),
+                    name: "ssl_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("GET".to_string()),
+                    description: "HTTP method for a client to send requests to the authorization service. When set to POST the request body is sent to the authorization service.".to_string(),
+                    name: "request_method".to_string(),
+                    property_type: PropertyType::Enum(vec!["GET".to_string(), "POST".to_string()]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Client request headers that should be sent to the authorization service. If not set, only the headers provided by APISIX are sent.".to_string(),
+                    name: "request_headers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Authorization service response headers that should be sent to the Upstream when the authorization succeeds.".to_string(),
+                    name: "upstream_headers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Authorization service response headers that should be sent to the client when the authorization fails.".to_string(),
+                    name: "client_headers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3000".to_string()),
+                    description: "Timeout in ms for the authorization service HTTP call.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, keeps the connection alive for multiple requests.".to_string(),
+                    name: "keepalive".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("60000".to_string()),
+                    description: "Keepalive idle timeout in ms.".to_string(),
+                    name: "keepalive_timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("5".to_string()),
+                    description: "Maximum number of connections in the keepalive pool.".to_string(),
+                    name: "keepalive_pool".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, allows authentication to be skipped when the authorization service is unavailable.".to_string(),
+                    name: "allow_degradation".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("403".to_string()),
+                    description: "Sets the status code returned to the client when the authorization service is unreachable.".to_string(),
+                    name: "status_on_error".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
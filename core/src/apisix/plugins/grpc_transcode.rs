@@ -0,0 +1,82 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct GrpcTranscodePlugin(pub PluginDefinition);}
+
+impl GrpcTranscodePlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "grpc-transcode".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Id of the proto content.".to_string(),
+                    is_required: Required::True,
+                    name: "proto_id".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Name of the gRPC service.".to_string(),
+                    is_required: Required::True,
+                    name: "service".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Method name of the gRPC service.".to_string(),
+                    is_required: Required::True,
+                    name: "method".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("0".to_string()),
+                    description: "Deadline for the gRPC service in ms.".to_string(),
+                    name: "deadline".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some(
+                        r#"["enum_as_name","int64_as_number","auto_default_values","disable_hooks"]"#
+                            .to_string(),
+                    ),
+                    description: "Protocol buffer options.".to_string(),
+                    name: "pb_option".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::Enum(vec![
+                        "enum_as_name".to_string(),
+                        "enum_as_value".to_string(),
+                        "int64_as_number".to_string(),
+                        "int64_as_string".to_string(),
+                        "int64_as_hexstring".to_string(),
+                        "auto_default_values".to_string(),
+                        "no_default_values".to_string(),
+                        "use_default_values".to_string(),
+                        "use_default_metatable".to_string(),
+                        "enable_hooks".to_string(),
+                        "disable_hooks".to_string(),
+                    ]))),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, shows the parsed grpc-status-details-bin in the response body.".to_string(),
+                    name: "show_status_in_body".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Message type of the details part in the error status. If not set, the error details are not decoded.".to_string(),
+                    name: "status_detail_type".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
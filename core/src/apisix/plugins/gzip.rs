@@ -0,0 +1,77 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct GzipPlugin(pub PluginDefinition);}
+
+impl GzipPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "gzip".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some(r#"["text/html"]"#.to_string()),
+                    description: "Dynamically sets the gzip_types directive. Use \"*\" to match all MIME types.".to_string(),
+                    name: "types".to_string(),
+                    property_type: PropertyType::JSON,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("20".to_string()),
+                    description: "Dynamically sets the gzip_min_length directive.".to_string(),
+                    name: "min_length".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1".to_string()),
+                    description: "Dynamically sets the gzip_comp_level directive, between 1 and 9.".to_string(),
+                    name: "comp_level".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1.1".to_string()),
+                    description: "Dynamically sets the gzip_http_version directive.".to_string(),
+                    name: "http_version".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Dynamically sets the gzip_buffers directive.".to_string(),
+                    name: "buffers".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some("32".to_string()),
+                            name: "number".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("4096".to_string()),
+                            name: "size".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "Dynamically sets the gzip_vary directive.".to_string(),
+                    name: "vary".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
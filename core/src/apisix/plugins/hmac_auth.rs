@@ -0,0 +1,100 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required, VersionRange},
+    macros::derive_common_default,
+};
+
+// The plugin was rewritten in 3.11, the older versions use an `access_key` and configure the
+// signature in the consumer
+const REWRITE_VERSION: &str = "3.11.0";
+
+derive_common_default! {
+pub struct RouteHmacAuthPlugin(pub PluginDefinition);}
+
+impl RouteHmacAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "hmac-auth".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some(r#"["hmac-sha1","hmac-sha256","hmac-sha512"]"#.to_string()),
+                    description: "List of HMAC algorithms to be allowed.".to_string(),
+                    name: "allowed_algorithms".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::Enum(vec![
+                        "hmac-sha1".to_string(),
+                        "hmac-sha256".to_string(),
+                        "hmac-sha512".to_string(),
+                    ]))),
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("300".to_string()),
+                    description: "Maximum allowable time difference in seconds between the client request's timestamp and APISIX server's current time.".to_string(),
+                    name: "clock_skew".to_string(),
+                    property_type: PropertyType::Number,
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "List of HMAC-signed headers that should be included in the client request's HMAC signature.".to_string(),
+                    name: "signed_headers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If true, validate the integrity of the request body to ensure it has not been tampered with.".to_string(),
+                    name: "validate_request_body".to_string(),
+                    property_type: PropertyType::Boolean,
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If true, do not pass the authorization request header to the Upstream.".to_string(),
+                    name: "hide_credentials".to_string(),
+                    property_type: PropertyType::Boolean,
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
+
+derive_common_default! {
+pub struct ConsumerHmacAuthPlugin(pub PluginDefinition);}
+
+impl ConsumerHmacAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "hmac-auth".to_string(),
+            entities: [PluginEntities::Consumer].iter().cloned().collect(),
+            options: vec![
+                PluginOption {
+                    description: "Unique identifier for the Consumer, which identifies the associated configurations such as the secret key.".to_string(),
+                    is_required: Required::True,
+                    name: "key_id".to_string(),
+                    version_range: Some(VersionRange::since(REWRITE_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Secret key used to generate the HMAC. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
+                    is_required: Required::True,
+                    is_secret: true,
+                    name: "secret_key".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, include_resp_body_option, log_format_option,
+    PluginDefinition, PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct HttpLoggerPlugin(pub PluginDefinition);}
+
+impl HttpLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "http-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "URI of the HTTP/HTTPS server.".to_string(),
+                    is_required: Required::True,
+                    name: "uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Authorization headers if required.".to_string(),
+                    is_secret: true,
+                    name: "auth_header".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3".to_string()),
+                    description: "Time to keep the connection alive for after sending a request.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                log_format_option(),
+                include_req_body_option(),
+                include_resp_body_option(),
+                PluginOption {
+                    default_value: Some("json".to_string()),
+                    description: "Sets how to concatenate logs. When set to json, uses a JSON array and when set to new_line, uses a new line.".to_string(),
+                    name: "concat_method".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "json".to_string(),
+                        "new_line".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true verifies the SSL certificate.".to_string(),
+                    name: "ssl_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ]
+            .into_iter()
+            .chain(batch_processor_options("http logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
@@ -1,8 +1,9 @@
 use super::common::{PluginDefinition, PluginEntities, PluginOption};
 use crate::{apisix::base::PropertyType, macros::derive_common_default};
+use std::net::IpAddr;
 
 derive_common_default! {
-pub struct IpRestrictionPlugin(PluginDefinition);}
+pub struct IpRestrictionPlugin(pub PluginDefinition);}
 
 impl IpRestrictionPlugin {
     pub fn new() -> Self {
@@ -36,6 +37,80 @@ impl IpRestrictionPlugin {
                     ..Default::default()
                 },
             ],
+            ..Default::default()
         })
     }
 }
+
+/// Accepts IPv4 and IPv6 addresses, with or without a CIDR prefix length
+pub fn is_ip_or_cidr(value: &str) -> bool {
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+
+    let Ok(address) = address.parse::<IpAddr>() else {
+        return false;
+    };
+
+    let Some(prefix) = prefix else {
+        return true;
+    };
+
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+
+    prefix.chars().all(|c| c.is_ascii_digit())
+        && prefix
+            .parse::<u8>()
+            .is_ok_and(|prefix| prefix <= max_prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_ip_or_cidr() {
+        for value in [
+            "10.0.0.1",
+            "10.0.0.0/8",
+            "0.0.0.0/0",
+            "192.168.1.1/32",
+            "10.0.0.0/08",
+            "::1",
+            "::/0",
+            "fe80::/10",
+            "2001:db8::/128",
+            "::ffff:192.168.1.1",
+            "::ffff:192.168.1.0/120",
+        ] {
+            assert!(is_ip_or_cidr(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_ip_or_cidr() {
+        for value in [
+            "",
+            "abc",
+            "10.0.0",
+            "10.0.0.256",
+            "01.2.3.4",
+            " 10.0.0.1",
+            "10.0.0.0/",
+            "10.0.0.0/33",
+            "10.0.0.0/+8",
+            "10.0.0.0/-1",
+            "10.0.0.0/256",
+            "10.0.0.0/8/8",
+            "/8",
+            "::/129",
+            "::/1000",
+            "fe80::1%eth0",
+            "2001:db8:::1",
+            "2001:db8::/ 64",
+        ] {
+            assert!(!is_ip_or_cidr(value), "{value}");
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RouteJwtAuthPlugin(pub PluginDefinition);}
+
+impl RouteJwtAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "jwt-auth".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("authorization".to_string()),
+                    description: "The header to get the token from.".to_string(),
+                    name: "header".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("jwt".to_string()),
+                    description: "The query string to get the token from. Lower priority than header.".to_string(),
+                    name: "query".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("jwt".to_string()),
+                    description: "The cookie to get the token from. Lower priority than query.".to_string(),
+                    name: "cookie".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "Set to true will not pass the header, query, or cookie with JWT to the Upstream.".to_string(),
+                    name: "hide_credentials".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("key".to_string()),
+                    description: "The name of the JWT claim that contains the user key (corresponds to Consumer's key attribute).".to_string(),
+                    name: "key_claim_name".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "Set to true will store the JWT payload in the request context for other plugins to use.".to_string(),
+                    name: "store_in_ctx".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
+
+derive_common_default! {
+pub struct ConsumerJwtAuthPlugin(pub PluginDefinition);}
+
+impl ConsumerJwtAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "jwt-auth".to_string(),
+            entities: [PluginEntities::Consumer].iter().cloned().collect(),
+            options: vec![
+                PluginOption {
+                    description: "Unique key for a Consumer.".to_string(),
+                    is_required: Required::True,
+                    name: "key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "The encryption key used for the HS256 and HS512 algorithms. If unspecified, it is auto generated. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
+                    is_secret: true,
+                    name: "secret".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "RSA or ECDSA public key. Required when using the RS256 or ES256 algorithms.".to_string(),
+                    name: "public_key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("HS256".to_string()),
+                    description: "Encryption algorithm.".to_string(),
+                    name: "algorithm".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "HS256".to_string(),
+                        "HS512".to_string(),
+                        "RS256".to_string(),
+                        "ES256".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("86400".to_string()),
+                    description: "Expiry time of the token in seconds.".to_string(),
+                    name: "exp".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "Set to true if the secret is base64 encoded.".to_string(),
+                    name: "base64_secret".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("0".to_string()),
+                    description: "Grace period in seconds to account for the clock skew between the server generating the JWT and the server validating it.".to_string(),
+                    name: "lifetime_grace_period".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, include_resp_body_option, log_format_option,
+    PluginDefinition, PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct KafkaLoggerPlugin(pub PluginDefinition);}
+
+impl KafkaLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "kafka-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "List of Kafka brokers.".to_string(),
+                    is_required: Required::True,
+                    name: "brokers".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::Object(vec![
+                        PropertyField {
+                            description: "The host of the Kafka broker.".to_string(),
+                            is_required: Required::True,
+                            name: "host".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "The port of the Kafka broker.".to_string(),
+                            is_required: Required::True,
+                            name: "port".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "The SASL config of the Kafka broker.".to_string(),
+                            name: "sasl_config".to_string(),
+                            property_type: PropertyType::Object(vec![
+                                PropertyField {
+                                    default_value: Some("PLAIN".to_string()),
+                                    description: "The mechanism of the SASL config.".to_string(),
+                                    name: "mechanism".to_string(),
+                                    property_type: PropertyType::Enum(vec!["PLAIN".to_string()]),
+                                    ..Default::default()
+                                },
+                                PropertyField {
+                                    description: "The user of the SASL config.".to_string(),
+                                    is_required: Required::True,
+                                    name: "user".to_string(),
+                                    ..Default::default()
+                                },
+                                PropertyField {
+                                    description: "The password of the SASL config.".to_string(),
+                                    is_required: Required::True,
+                                    name: "password".to_string(),
+                                    sensitive: true,
+                                    ..Default::default()
+                                },
+                            ]),
+                            ..Default::default()
+                        },
+                    ]))),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Target topic to push the logs for organisation.".to_string(),
+                    is_required: Required::True,
+                    name: "kafka_topic".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("async".to_string()),
+                    description: "Message sending mode of the producer.".to_string(),
+                    name: "producer_type".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "async".to_string(),
+                        "sync".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1".to_string()),
+                    description: "Number of acknowledgements the leader needs to receive for the producer to consider the request complete. Can be 0, 1 or -1.".to_string(),
+                    name: "required_acks".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Key used for allocating partitions for messages.".to_string(),
+                    name: "key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3".to_string()),
+                    description: "Timeout for the upstream to send data.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("default".to_string()),
+                    description: "Format to collect the request information. Setting to default collects the information in JSON format and origin collects the information with the original HTTP request.".to_string(),
+                    name: "meta_format".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "default".to_string(),
+                        "origin".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                log_format_option(),
+                include_req_body_option(),
+                include_resp_body_option(),
+                PluginOption {
+                    default_value: Some("1".to_string()),
+                    description: "Name of the cluster. Used when there are two or more Kafka clusters.".to_string(),
+                    name: "cluster_name".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+            ]
+            .into_iter()
+            .chain(batch_processor_options("kafka logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
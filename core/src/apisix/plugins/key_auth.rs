@@ -5,7 +5,7 @@ use crate::{
 };
 
 derive_common_default! {
-pub struct RouteKeyAuthPlugin(PluginDefinition);}
+pub struct RouteKeyAuthPlugin(pub PluginDefinition);}
 
 impl RouteKeyAuthPlugin {
     pub fn new() -> Self {
@@ -20,31 +20,41 @@ impl RouteKeyAuthPlugin {
                     default_value: Some("apikey".to_string()),
                     description: "The header to get the key from.".to_string(),
                     is_required: Required::False,
+                    is_secret: false,
                     name: "header".to_string(),
                     property_type: PropertyType::String,
+                    sensitive: false,
+                    version_range: None,
                 },
                 PluginOption {
                     default_value: Some("apikey".to_string()),
                     description:
                         "The query string to get the key from. Lower priority than header.".to_string(),
                     is_required: Required::False,
+                    is_secret: false,
                     name: "query".to_string(),
                     property_type: PropertyType::String,
+                    sensitive: false,
+                    version_range: None,
                 },
                 PluginOption {
                     default_value: Some("false".to_string()),
                     description: "Apache APISIX will pass the request header or query string that contains the authentication information to the Upstream if hide_credentials is false. Otherwise the authentication information will be removed before proxying.".to_string(),
                     is_required: Required::False,
+                    is_secret: false,
                     name: "hide_credentials".to_string(),
                     property_type: PropertyType::Boolean,
+                    sensitive: false,
+                    version_range: None,
                 },
             ],
+            ..Default::default()
         })
     }
 }
 
 derive_common_default! {
-pub struct ConsumerKeyAuthPlugin(PluginDefinition);}
+pub struct ConsumerKeyAuthPlugin(pub PluginDefinition);}
 
 impl ConsumerKeyAuthPlugin {
     pub fn new() -> Self {
@@ -59,10 +69,14 @@ impl ConsumerKeyAuthPlugin {
                     default_value: None,
                     description: "Unique key for a Consumer. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
                     is_required: Required::True,
+                    is_secret: true,
                     name: "key".to_string(),
                     property_type: PropertyType::String,
+                    sensitive: true,
+                    version_range: None,
                 },
             ],
+            ..Default::default()
         })
     }
 }
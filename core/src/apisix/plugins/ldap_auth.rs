@@ -0,0 +1,80 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RouteLdapAuthPlugin(pub PluginDefinition);}
+
+impl RouteLdapAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "ldap-auth".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description:
+                        "Base dn of the LDAP server. For example, ou=users,dc=example,dc=org."
+                            .to_string(),
+                    is_required: Required::True,
+                    name: "base_dn".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URI of the LDAP server.".to_string(),
+                    is_required: Required::True,
+                    name: "ldap_uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If set to true uses TLS.".to_string(),
+                    name: "use_tls".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description:
+                        "Whether to verify the server certificate when use_tls is enabled."
+                            .to_string(),
+                    name: "tls_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("cn".to_string()),
+                    description: "uid attribute.".to_string(),
+                    name: "uid".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
+
+derive_common_default! {
+pub struct ConsumerLdapAuthPlugin(pub PluginDefinition);}
+
+impl ConsumerLdapAuthPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "ldap-auth".to_string(),
+            entities: [PluginEntities::Consumer].iter().cloned().collect(),
+            options: vec![PluginOption {
+                description:
+                    "User dn of the LDAP client. For example, cn=user01,ou=users,dc=example,dc=org."
+                        .to_string(),
+                is_required: Required::True,
+                name: "user_dn".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+}
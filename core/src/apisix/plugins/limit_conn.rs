@@ -0,0 +1,122 @@
+use super::{
+    common::{PluginDefinition, PluginEntities, PluginOption},
+    limit_count::redis_options,
+};
+use crate::{
+    apisix::base::{PropertyType, Required, VersionRange},
+    macros::derive_common_default,
+};
+
+// The older versions only count the connections of each node
+const REDIS_VERSION: &str = "3.11.0";
+
+derive_common_default! {
+pub struct LimitConnPlugin(pub PluginDefinition);}
+
+impl LimitConnPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "limit-conn".to_string(),
+            entities: [
+                PluginEntities::Route,
+                PluginEntities::Service,
+                PluginEntities::Consumer,
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Maximum number of concurrent requests allowed. Requests exceeding this ratio (and below conn + burst) will be delayed.".to_string(),
+                    is_required: Required::True,
+                    name: "conn".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Number of additional concurrent requests allowed to be delayed per second. If the number exceeds this hard limit, they will get rejected immediately.".to_string(),
+                    is_required: Required::True,
+                    name: "burst".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Processing latency of a request in seconds. Based on this value, the delay time of excessive requests is calculated.".to_string(),
+                    is_required: Required::True,
+                    name: "default_conn_delay".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If set to true, the delay time of excessive requests is calculated with default_conn_delay only.".to_string(),
+                    name: "only_use_default_delay".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("var".to_string()),
+                    description: "Type of user specified key to use.".to_string(),
+                    name: "key_type".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "var".to_string(),
+                        "var_combination".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "User specified key to base the request limiting on, like remote_addr or consumer_name.".to_string(),
+                    is_required: Required::True,
+                    name: "key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("503".to_string()),
+                    description: "HTTP status code returned when the requests exceeding the threshold are rejected.".to_string(),
+                    name: "rejected_code".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Body of the response returned when the requests exceeding the threshold are rejected.".to_string(),
+                    name: "rejected_msg".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true enables Plugin degradation when the Plugin is temporarily unavailable and allows requests to continue.".to_string(),
+                    name: "allow_degradation".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("local".to_string()),
+                    description: "Rate-limiting policy. When set to redis or redis-cluster the state is shared across the nodes.".to_string(),
+                    name: "policy".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "local".to_string(),
+                        "redis".to_string(),
+                        "redis-cluster".to_string(),
+                    ]),
+                    version_range: Some(VersionRange::since(REDIS_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3600".to_string()),
+                    description: "The TTL of the Redis key in seconds. Used when the policy is set to redis or redis-cluster.".to_string(),
+                    name: "key_ttl".to_string(),
+                    property_type: PropertyType::Number,
+                    version_range: Some(VersionRange::since(REDIS_VERSION)),
+                    ..Default::default()
+                },
+            ]
+            .into_iter()
+            .chain(redis_options().into_iter().map(|option| PluginOption {
+                version_range: Some(VersionRange::since(REDIS_VERSION)),
+                ..option
+            }))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 
 derive_common_default! {
-pub struct LimitCountPlugin(PluginDefinition);}
+pub struct LimitCountPlugin(pub PluginDefinition);}
 
 impl LimitCountPlugin {
     pub fn new() -> Self {
@@ -87,75 +87,87 @@ impl LimitCountPlugin {
                     name: "group".to_string(),
                     ..Default::default()
                 },
-                PluginOption {
-                    description: "Address of the Redis server. Used when the policy attribute is set to redis.".to_string(),
-                    name: "redis_host".to_string(),
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Port of the Redis server. Used when the policy attribute is set to redis.".to_string(),
-                    name: "redis_port".to_string(),
-                    property_type: PropertyType::Number,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Username for Redis authentication if Redis ACL is used (for Redis version >= 6.0). If you use the legacy authentication method requirepass to configure Redis password, configure only the redis_password. Used when the policy is set to redis.".to_string(),
-                    name: "redis_username".to_string(),
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Password for Redis authentication. Used when the policy is set to redis or redis-cluster.".to_string(),
-                    name: "redis_password".to_string(),
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "If set to true, then uses SSL to connect to redis instance. Used when the policy attribute is set to redis.".to_string(),
-                    name: "redis_ssl".to_string(),
-                    property_type: PropertyType::Boolean,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "If set to true, then verifies the validity of the server SSL certificate. Used when the policy attribute is set to redis. See tcpsock:sslhandshake.".to_string(),
-                    name: "redis_ssl_verify".to_string(),
-                    property_type: PropertyType::Boolean,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Selected database of the Redis server (for single instance operation or when using Redis cloud with a single entrypoint). Used when the policy attribute is set to redis.".to_string(),
-                    name: "redis_database".to_string(),
-                    property_type: PropertyType::Number,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Timeout in milliseconds for any command submitted to the Redis server. Used when the policy attribute is set to redis or redis-cluster.".to_string(),
-                    name: "redis_timeout".to_string(),
-                    property_type: PropertyType::Number,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Addresses of Redis cluster nodes. Used when the policy attribute is set to redis-cluster.".to_string(),
-                    name: "redis_cluster_nodes".to_string(),
-                    property_type: PropertyType::List(Box::new(PropertyType::String)),
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "Name of the Redis cluster service nodes. Used when the policy attribute is set to redis-cluster.".to_string(),
-                    name: "redis_cluster_name".to_string(),
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "If set to true, then uses SSL to connect to redis-cluster. Used when the policy attribute is set to redis-cluster.".to_string(),
-                    name: "redis_cluster_ssl".to_string(),
-                    property_type: PropertyType::Boolean,
-                    ..Default::default()
-                },
-                PluginOption {
-                    description: "If set to true, then verifies the validity of the server SSL certificate. Used when the policy attribute is set to redis-cluster.".to_string(),
-                    name: "redis_cluster_ssl_verify".to_string(),
-                    property_type: PropertyType::Boolean,
-                    ..Default::default()
-                },
-            ],
+            ]
+            .into_iter()
+            .chain(redis_options())
+            .collect(),
+            ..Default::default()
         })
     }
 }
+
+// Shared by the rate limiting plugins that support the `redis` and `redis-cluster` policies
+pub fn redis_options() -> Vec<PluginOption> {
+    vec![
+        PluginOption {
+            description: "Address of the Redis server. Used when the policy attribute is set to redis.".to_string(),
+            name: "redis_host".to_string(),
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Port of the Redis server. Used when the policy attribute is set to redis.".to_string(),
+            name: "redis_port".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Username for Redis authentication if Redis ACL is used (for Redis version >= 6.0). If you use the legacy authentication method requirepass to configure Redis password, configure only the redis_password. Used when the policy is set to redis.".to_string(),
+            name: "redis_username".to_string(),
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Password for Redis authentication. Used when the policy is set to redis or redis-cluster.".to_string(),
+            is_secret: true,
+            name: "redis_password".to_string(),
+            sensitive: true,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "If set to true, then uses SSL to connect to redis instance. Used when the policy attribute is set to redis.".to_string(),
+            name: "redis_ssl".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "If set to true, then verifies the validity of the server SSL certificate. Used when the policy attribute is set to redis. See tcpsock:sslhandshake.".to_string(),
+            name: "redis_ssl_verify".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Selected database of the Redis server (for single instance operation or when using Redis cloud with a single entrypoint). Used when the policy attribute is set to redis.".to_string(),
+            name: "redis_database".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Timeout in milliseconds for any command submitted to the Redis server. Used when the policy attribute is set to redis or redis-cluster.".to_string(),
+            name: "redis_timeout".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Addresses of Redis cluster nodes. Used when the policy attribute is set to redis-cluster.".to_string(),
+            name: "redis_cluster_nodes".to_string(),
+            property_type: PropertyType::List(Box::new(PropertyType::String)),
+            ..Default::default()
+        },
+        PluginOption {
+            description: "Name of the Redis cluster service nodes. Used when the policy attribute is set to redis-cluster.".to_string(),
+            name: "redis_cluster_name".to_string(),
+            ..Default::default()
+        },
+        PluginOption {
+            description: "If set to true, then uses SSL to connect to redis-cluster. Used when the policy attribute is set to redis-cluster.".to_string(),
+            name: "redis_cluster_ssl".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+        PluginOption {
+            description: "If set to true, then verifies the validity of the server SSL certificate. Used when the policy attribute is set to redis-cluster.".to_string(),
+            name: "redis_cluster_ssl_verify".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+    ]
+}
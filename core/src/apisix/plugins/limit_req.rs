@@ -0,0 +1,100 @@
+use super::{
+    common::{PluginDefinition, PluginEntities, PluginOption},
+    limit_count::redis_options,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct LimitReqPlugin(pub PluginDefinition);}
+
+impl LimitReqPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "limit-req".to_string(),
+            entities: [
+                PluginEntities::Route,
+                PluginEntities::Service,
+                PluginEntities::Consumer,
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Threshold for number of requests per second. Requests exceeding this rate (and below burst) will be delayed to match this rate.".to_string(),
+                    is_required: Required::True,
+                    name: "rate".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Number of additional requests allowed to be delayed per second. If the number of requests exceeds this hard limit, they will get rejected immediately.".to_string(),
+                    is_required: Required::True,
+                    name: "burst".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("var".to_string()),
+                    description: "Type of user specified key to use.".to_string(),
+                    name: "key_type".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "var".to_string(),
+                        "var_combination".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "User specified key to base the request limiting on, like remote_addr or consumer_name.".to_string(),
+                    is_required: Required::True,
+                    name: "key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("503".to_string()),
+                    description: "HTTP status code returned when the requests exceeding the threshold are rejected.".to_string(),
+                    name: "rejected_code".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Body of the response returned when the requests exceeding the threshold are rejected.".to_string(),
+                    name: "rejected_msg".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If set to true, requests within the burst threshold would not be delayed.".to_string(),
+                    name: "nodelay".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true enables Plugin degradation when the Plugin is temporarily unavailable and allows requests to continue.".to_string(),
+                    name: "allow_degradation".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("local".to_string()),
+                    description: "Rate-limiting policy. When set to redis or redis-cluster the state is shared across the nodes.".to_string(),
+                    name: "policy".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "local".to_string(),
+                        "redis".to_string(),
+                        "redis-cluster".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+            ]
+            .into_iter()
+            .chain(redis_options())
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
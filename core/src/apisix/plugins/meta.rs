@@ -0,0 +1,116 @@
+use super::common::PluginOption;
+use crate::{
+    apisix::base::{PropertyField, PropertyType},
+    macros::derive_common_default,
+};
+use serde_json::{Map, Value};
+use ts_rs::TS;
+
+pub const META_KEY: &str = "_meta";
+
+// Options that APISIX accepts in the config of every plugin, under the `_meta` key
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct PluginMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(type = "unknown")]
+    pub error_response: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(type = "unknown")]
+    pub filter: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(type = "number | null")]
+    pub priority: Option<i64>,
+}}
+
+impl PluginMeta {
+    pub fn from_config(config: &Value) -> Option<Self> {
+        serde_json::from_value(config.get(META_KEY)?.clone()).ok()
+    }
+
+    pub fn is_disabled(config: &Value) -> bool {
+        Self::from_config(config)
+            .and_then(|meta| meta.disable)
+            .unwrap_or(false)
+    }
+}
+
+pub fn meta_option() -> PluginOption {
+    PluginOption {
+        description: "Common options of all plugins: disable it without removing the config, run it only for some requests, change its priority or its error response.".to_string(),
+        name: META_KEY.to_string(),
+        property_type: PropertyType::Object(vec![
+            PropertyField {
+                default_value: Some("false".to_string()),
+                description: "When set to true, the plugin is not executed but its config is kept.".to_string(),
+                name: "disable".to_string(),
+                property_type: PropertyType::Boolean,
+                ..Default::default()
+            },
+            PropertyField {
+                description: "List of vars expressions, with the same format as the vars of a Route. The plugin is only executed for the requests that match.".to_string(),
+                name: "filter".to_string(),
+                property_type: PropertyType::JSON,
+                ..Default::default()
+            },
+            PropertyField {
+                description: "Overrides the priority of the plugin in the current entity.".to_string(),
+                name: "priority".to_string(),
+                property_type: PropertyType::Number,
+                ..Default::default()
+            },
+            PropertyField {
+                description: "Fixed response (a string or a JSON object) returned instead of the error of the plugin.".to_string(),
+                name: "error_response".to_string(),
+                property_type: PropertyType::JSON,
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    }
+}
+
+// Keeps the rest of the config, so the plugin can be enabled again as it was
+pub fn set_plugin_disabled(
+    plugins: &mut Map<String, Value>,
+    name: &str,
+    disabled: bool,
+) -> Result<(), String> {
+    let config = plugins
+        .get_mut(name)
+        .ok_or_else(|| format!("Plugin {} is not configured", name))?;
+
+    // Plugins without options can be set as null in the standalone config
+    if config.is_null() {
+        *config = Value::Object(Map::new());
+    }
+
+    let config = config
+        .as_object_mut()
+        .ok_or_else(|| format!("Config of plugin {} is not an object", name))?;
+
+    if disabled {
+        let meta = config
+            .entry(META_KEY)
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if !meta.is_object() {
+            *meta = Value::Object(Map::new());
+        }
+
+        meta.as_object_mut()
+            .unwrap()
+            .insert("disable".to_string(), Value::Bool(true));
+    } else if let Some(meta) = config.get_mut(META_KEY).and_then(Value::as_object_mut) {
+        meta.remove("disable");
+
+        if meta.is_empty() {
+            config.remove(META_KEY);
+        }
+    }
+
+    Ok(())
+}
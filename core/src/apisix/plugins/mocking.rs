@@ -0,0 +1,69 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct MockingPlugin(pub PluginDefinition);}
+
+impl MockingPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "mocking".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("0".to_string()),
+                    description: "Response delay in seconds.".to_string(),
+                    name: "delay".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("200".to_string()),
+                    description: "HTTP status code of the response.".to_string(),
+                    name: "response_status".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("application/json;charset=utf8".to_string()),
+                    description: "Header Content-Type of the response.".to_string(),
+                    name: "content_type".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Body of the response, support use built-in variables in NGINX like $remote_addr $consumer_name.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["response_schema".to_string()]),
+                    name: "response_example".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "The JSON schema object for the response. Works when response_example is unspecified.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["response_example".to_string()]),
+                    name: "response_schema".to_string(),
+                    property_type: PropertyType::JSON,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, adds a response header x-mock-by: APISIX/{version}.".to_string(),
+                    name: "with_mock_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Headers to be added in the mocked response.".to_string(),
+                    name: "response_headers".to_string(),
+                    property_type: PropertyType::Map(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
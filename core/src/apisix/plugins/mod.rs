@@ -1,14 +1,56 @@
+pub mod api_breaker;
+pub mod authz_keycloak;
 pub mod basic_auth;
+pub mod body_transformer;
+pub mod brotli;
+pub mod chaitin_waf;
 pub mod common;
 pub mod consumer_restriction;
 pub mod cors;
+pub mod csrf;
+pub mod degraphql;
+pub mod elasticsearch_logger;
+pub mod ext_plugin_post_req;
+pub mod ext_plugin_post_resp;
 pub mod ext_plugin_pre_req;
+pub mod fault_injection;
+pub mod file_logger;
+pub mod forward_auth;
+pub mod grpc_transcode;
+pub mod gzip;
+pub mod hmac_auth;
+pub mod http_logger;
 pub mod ip_restriction;
+pub mod jwt_auth;
+pub mod kafka_logger;
 pub mod key_auth;
+pub mod ldap_auth;
+pub mod limit_conn;
 pub mod limit_count;
+pub mod limit_req;
+pub mod meta;
+pub mod mocking;
+pub mod openid_connect;
+pub mod opentelemetry;
 pub mod prometheus;
 pub mod proxy_mirror;
 pub mod proxy_rewrite;
 pub mod public_api;
+pub mod real_ip;
+pub mod redirect;
+pub mod referer_restriction;
+pub mod registry;
+pub mod request_id;
+pub mod request_validation;
 pub mod response_rewrite;
+pub mod serverless;
+pub mod skywalking;
+pub mod syslog;
+pub mod tcp_logger;
+pub mod traffic_split;
+pub mod ua_restriction;
+pub mod udp_logger;
 pub mod uri_blocker;
+pub mod wasm_plugins;
+pub mod workflow;
+pub mod zipkin;
@@ -0,0 +1,191 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct OpenidConnectPlugin(pub PluginDefinition);}
+
+impl OpenidConnectPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "openid-connect".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "OAuth client ID.".to_string(),
+                    is_required: Required::True,
+                    name: "client_id".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "OAuth client secret. This field supports saving the value in Secret Manager using the APISIX Secret resource.".to_string(),
+                    is_required: Required::True,
+                    is_secret: true,
+                    name: "client_secret".to_string(),
+                    sensitive: true,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Discovery endpoint URL of the identity server.".to_string(),
+                    is_required: Required::True,
+                    name: "discovery".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("openid".to_string()),
+                    description: "OIDC scope that corresponds to information that should be returned about the authenticated user.".to_string(),
+                    name: "scope".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Scopes required to be present in the access token.".to_string(),
+                    name: "required_scopes".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("apisix".to_string()),
+                    description: "Realm in WWW-Authenticate response header accompanying a 401 unauthorized request due to invalid bearer token.".to_string(),
+                    name: "realm".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, APISIX will only check if the authorization header in the request matches a bearer token.".to_string(),
+                    name: "bearer_only".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("auth".to_string()),
+                    description: "Action for unauthenticated requests. When set to auth, redirects to the authentication endpoint of the OpenID provider. When set to pass, allows the request without authentication. When set to deny, returns 401 unauthenticated responses.".to_string(),
+                    name: "unauth_action".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "auth".to_string(),
+                        "deny".to_string(),
+                        "pass".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("/logout".to_string()),
+                    description: "Path to activate the logout.".to_string(),
+                    name: "logout_path".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URL to redirect to after logout.".to_string(),
+                    name: "post_logout_redirect_uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URI to which the identity provider redirects back to. If not configured, APISIX will append .apisix/redirect to the request URI.".to_string(),
+                    name: "redirect_uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3".to_string()),
+                    description: "Request timeout time in seconds.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, verifies the identity provider's SSL certificates.".to_string(),
+                    name: "ssl_verify".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URL of the token introspection endpoint for the OpenID provider used to introspect access tokens.".to_string(),
+                    name: "introspection_endpoint".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("client_secret_basic".to_string()),
+                    description: "Authentication method for the token introspection endpoint.".to_string(),
+                    name: "introspection_endpoint_auth_method".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Authentication method for the token endpoint.".to_string(),
+                    name: "token_endpoint_auth_method".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Public key used to verify JWT signature if an asymmetric algorithm is used.".to_string(),
+                    name: "public_key".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "If true, use the JWKS endpoint of the identity server to verify the token.".to_string(),
+                    name: "use_jwks".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, the Proof Key for Code Exchange (PKCE) is used.".to_string(),
+                    name: "use_pkce".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true, sets the access token in the X-Access-Token request header.".to_string(),
+                    name: "set_access_token_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true and set_access_token_header is also true, sets the access token in the Authorization header.".to_string(),
+                    name: "access_token_in_authorization_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true and the ID token is available, sets the ID token in the X-ID-Token request header.".to_string(),
+                    name: "set_id_token_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description: "When set to true and the UserInfo object is available, sets it in the X-Userinfo request header.".to_string(),
+                    name: "set_userinfo_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true and a refresh token object is available, sets it in the X-Refresh-Token request header.".to_string(),
+                    name: "set_refresh_token_header".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Session configuration used when bearer_only is false.".to_string(),
+                    name: "session".to_string(),
+                    property_type: PropertyType::Object(vec![PropertyField {
+                        description: "Secret used for session encryption and HMAC operation. It must be at least 16 characters long.".to_string(),
+                        is_required: Required::True,
+                        name: "secret".to_string(),
+                        sensitive: true,
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct OpentelemetryPlugin(pub PluginDefinition);}
+
+impl OpentelemetryPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "opentelemetry".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Sampling configuration. The collector is configured in config.yaml.".to_string(),
+                    name: "sampler".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some("always_off".to_string()),
+                            description: "Sampling strategy.".to_string(),
+                            name: "name".to_string(),
+                            property_type: PropertyType::Enum(vec![
+                                "always_on".to_string(),
+                                "always_off".to_string(),
+                                "trace_id_ratio".to_string(),
+                                "parent_base".to_string(),
+                            ]),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Parameters of the sampling strategy, like fraction for trace_id_ratio or root for parent_base.".to_string(),
+                            name: "options".to_string(),
+                            property_type: PropertyType::JSON,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Variables and their values to add to the trace span attributes.".to_string(),
+                    name: "additional_attributes".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Headers or header prefixes to add to the trace span attributes.".to_string(),
+                    name: "additional_header_prefix_attributes".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
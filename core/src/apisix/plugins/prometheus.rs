@@ -2,7 +2,7 @@ use super::common::{PluginDefinition, PluginEntities, PluginOption};
 use crate::{apisix::base::PropertyType, macros::derive_common_default};
 
 derive_common_default! {
-pub struct PrometheusPlugin(PluginDefinition);}
+pub struct PrometheusPlugin(pub PluginDefinition);}
 
 impl PrometheusPlugin {
     pub fn new() -> Self {
@@ -20,6 +20,7 @@ impl PrometheusPlugin {
                         ..Default::default()
                     }
                 ],
+            ..Default::default()
         })
     }
 }
@@ -5,7 +5,7 @@ use crate::{
 };
 
 derive_common_default! {
-pub struct ProxyMirrorPlugin(PluginDefinition);}
+pub struct ProxyMirrorPlugin(pub PluginDefinition);}
 
 impl ProxyMirrorPlugin {
     pub fn new() -> Self {
@@ -39,6 +39,7 @@ impl ProxyMirrorPlugin {
                         ..Default::default()
                     }
                 ],
+            ..Default::default()
         })
     }
 }
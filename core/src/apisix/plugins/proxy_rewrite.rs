@@ -1,8 +1,11 @@
 use super::common::{PluginDefinition, PluginEntities, PluginOption};
-use crate::{apisix::base::PropertyType, macros::derive_common_default};
+use crate::{
+    apisix::base::{PropertyField, PropertyType},
+    macros::derive_common_default,
+};
 
 derive_common_default! {
-pub struct ProxyRewritePlugin(PluginDefinition);}
+pub struct ProxyRewritePlugin(pub PluginDefinition);}
 
 impl ProxyRewritePlugin {
     pub fn new() -> Self {
@@ -21,6 +24,7 @@ impl ProxyRewritePlugin {
                     ..Default::default()
                 },
                 PluginOption {
+                    description: "Regular expression to match the URI of the client request, followed by the template for the new Upstream URI. For example, [\"^/iresty/(.*)/(.*)/(.*)\", \"/$1_$2_$3\"].".to_string(),
                     name: "regex_uri".to_string(),
                     property_type: PropertyType::List(Box::new(PropertyType::String)),
                     ..Default::default()
@@ -31,8 +35,28 @@ impl ProxyRewritePlugin {
                     ..Default::default()
                 },
                 PluginOption {
+                    description: "Headers of the request to the Upstream. The operations are executed in the order add, remove and set.".to_string(),
                     name: "headers".to_string(),
-                    property_type: PropertyType::JSON,
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "Appends the new headers. The value supports Nginx variables.".to_string(),
+                            name: "add".to_string(),
+                            property_type: PropertyType::Map(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Overwrites the headers. If the header does not exist, it will be added. The value supports Nginx variables.".to_string(),
+                            name: "set".to_string(),
+                            property_type: PropertyType::Map(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Removes the headers.".to_string(),
+                            name: "remove".to_string(),
+                            property_type: PropertyType::List(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                    ]),
                     ..Default::default()
                 },
                 PluginOption {
@@ -42,6 +66,7 @@ impl ProxyRewritePlugin {
                     ..Default::default()
                 }
             ],
+            ..Default::default()
         })
     }
 }
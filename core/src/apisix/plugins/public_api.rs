@@ -2,7 +2,7 @@ use super::common::{PluginDefinition, PluginEntities, PluginOption};
 use crate::macros::derive_common_default;
 
 derive_common_default! {
-pub struct PublicApiPlugin(PluginDefinition);}
+pub struct PublicApiPlugin(pub PluginDefinition);}
 
 impl PublicApiPlugin {
     pub fn new() -> Self {
@@ -14,6 +14,7 @@ impl PublicApiPlugin {
                 name: "uri".to_string(),
                 ..Default::default()
             }],
+            ..Default::default()
         })
     }
 }
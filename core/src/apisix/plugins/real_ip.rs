@@ -0,0 +1,42 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RealIpPlugin(pub PluginDefinition);}
+
+impl RealIpPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "real-ip".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Dynamically set the client's IP address and an optional port from APISIX's view, like arg_realip or http_x_forwarded_for.".to_string(),
+                    is_required: Required::True,
+                    name: "source".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Dynamically set the set_real_ip_from field, list of IPs or CIDR ranges.".to_string(),
+                    name: "trusted_addresses".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, the last non-trusted address in the source is used as the client address.".to_string(),
+                    name: "recursive".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
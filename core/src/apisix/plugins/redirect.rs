@@ -0,0 +1,59 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{apisix::base::PropertyType, macros::derive_common_default};
+
+derive_common_default! {
+pub struct RedirectPlugin(pub PluginDefinition);}
+
+impl RedirectPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "redirect".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true and the request is HTTP, it will be redirected to HTTPS with the same URI with a 301 status code.".to_string(),
+                    name: "http_to_https".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "URI to redirect to. Can contain Nginx variables like $uri.".to_string(),
+                    name: "uri".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Match the URL from client with a regular expression and redirect. The first item is the regex and the second is the replacement template.".to_string(),
+                    name: "regex_uri".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("302".to_string()),
+                    description: "HTTP response code.".to_string(),
+                    name: "ret_code".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true the URI in the Location header will be encoded as per RFC3986.".to_string(),
+                    name: "encode_uri".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, adds the query string from the original request to the Location header.".to_string(),
+                    name: "append_query_string".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
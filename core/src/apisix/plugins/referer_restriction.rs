@@ -0,0 +1,50 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RefererRestrictionPlugin(pub PluginDefinition);}
+
+impl RefererRestrictionPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "referer-restriction".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "List of hostnames to whitelist. A hostname can start with * for wildcard.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["blacklist".to_string()]),
+                    name: "whitelist".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "List of hostnames to blacklist. A hostname can start with * for wildcard.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["whitelist".to_string()]),
+                    name: "blacklist".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("Your referer host is not allowed".to_string()),
+                    description: "Message returned when access is not allowed.".to_string(),
+                    name: "message".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, bypasses the check when the Referer request header is missing or malformed.".to_string(),
+                    name: "bypass_missing".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
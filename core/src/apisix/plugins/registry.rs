@@ -0,0 +1,284 @@
+use super::{
+    api_breaker::ApiBreakerPlugin,
+    authz_keycloak::AuthzKeycloakPlugin,
+    basic_auth::{ConsumerBasicAuthPlugin, RouteBasicAuthPlugin},
+    body_transformer::BodyTransformerPlugin,
+    brotli::BrotliPlugin,
+    chaitin_waf::ChaitinWafPlugin,
+    common::{PluginDefinition, PluginEntities},
+    consumer_restriction::ConsumerRestrictionPlugin,
+    cors::CorsPlugin,
+    csrf::CsrfPlugin,
+    degraphql::DegraphqlPlugin,
+    elasticsearch_logger::ElasticsearchLoggerPlugin,
+    ext_plugin_post_req::ExtPluginPostReqPlugin,
+    ext_plugin_post_resp::ExtPluginPostRespPlugin,
+    ext_plugin_pre_req::ExtPluginPreReqPlugin,
+    fault_injection::FaultInjectionPlugin,
+    file_logger::FileLoggerPlugin,
+    forward_auth::ForwardAuthPlugin,
+    grpc_transcode::GrpcTranscodePlugin,
+    gzip::GzipPlugin,
+    hmac_auth::{ConsumerHmacAuthPlugin, RouteHmacAuthPlugin},
+    http_logger::HttpLoggerPlugin,
+    ip_restriction::IpRestrictionPlugin,
+    jwt_auth::{ConsumerJwtAuthPlugin, RouteJwtAuthPlugin},
+    kafka_logger::KafkaLoggerPlugin,
+    key_auth::{ConsumerKeyAuthPlugin, RouteKeyAuthPlugin},
+    ldap_auth::{ConsumerLdapAuthPlugin, RouteLdapAuthPlugin},
+    limit_conn::LimitConnPlugin,
+    limit_count::LimitCountPlugin,
+    limit_req::LimitReqPlugin,
+    meta::{meta_option, META_KEY},
+    mocking::MockingPlugin,
+    openid_connect::OpenidConnectPlugin,
+    opentelemetry::OpentelemetryPlugin,
+    prometheus::PrometheusPlugin,
+    proxy_mirror::ProxyMirrorPlugin,
+    proxy_rewrite::ProxyRewritePlugin,
+    public_api::PublicApiPlugin,
+    real_ip::RealIpPlugin,
+    redirect::RedirectPlugin,
+    referer_restriction::RefererRestrictionPlugin,
+    request_id::RequestIdPlugin,
+    request_validation::RequestValidationPlugin,
+    response_rewrite::ResponseRewritePlugin,
+    serverless::{ServerlessPostFunctionPlugin, ServerlessPreFunctionPlugin},
+    skywalking::SkywalkingPlugin,
+    syslog::SyslogPlugin,
+    tcp_logger::TcpLoggerPlugin,
+    traffic_split::TrafficSplitPlugin,
+    ua_restriction::UaRestrictionPlugin,
+    udp_logger::UdpLoggerPlugin,
+    uri_blocker::UriBlockerPlugin,
+    workflow::WorkflowPlugin,
+    zipkin::ZipkinPlugin,
+};
+use crate::{
+    apisix::{base::is_version_available, schema::ApisixSchema},
+    macros::derive_common_default,
+};
+
+// Owns the plugin definitions, the built-in ones plus the ones added at runtime (e.g. from the
+// `wasm.plugins` of the file config)
+derive_common_default! {
+pub struct PluginRegistry {
+    definitions: Vec<PluginDefinition>,
+}}
+
+impl PluginRegistry {
+    pub fn new() -> Self {
+        Self::from_definitions(vec![
+            ApiBreakerPlugin::new().0,
+            AuthzKeycloakPlugin::new().0,
+            BodyTransformerPlugin::new().0,
+            BrotliPlugin::new().0,
+            ChaitinWafPlugin::new().0,
+            ConsumerBasicAuthPlugin::new().0,
+            ConsumerHmacAuthPlugin::new().0,
+            ConsumerJwtAuthPlugin::new().0,
+            ConsumerKeyAuthPlugin::new().0,
+            ConsumerLdapAuthPlugin::new().0,
+            ConsumerRestrictionPlugin::new().0,
+            CorsPlugin::new().0,
+            CsrfPlugin::new().0,
+            DegraphqlPlugin::new().0,
+            ElasticsearchLoggerPlugin::new().0,
+            ExtPluginPostReqPlugin::new().0,
+            ExtPluginPostRespPlugin::new().0,
+            ExtPluginPreReqPlugin::new().0,
+            FaultInjectionPlugin::new().0,
+            FileLoggerPlugin::new().0,
+            ForwardAuthPlugin::new().0,
+            GrpcTranscodePlugin::new().0,
+            GzipPlugin::new().0,
+            HttpLoggerPlugin::new().0,
+            IpRestrictionPlugin::new().0,
+            KafkaLoggerPlugin::new().0,
+            LimitConnPlugin::new().0,
+            LimitCountPlugin::new().0,
+            LimitReqPlugin::new().0,
+            MockingPlugin::new().0,
+            OpenidConnectPlugin::new().0,
+            OpentelemetryPlugin::new().0,
+            PrometheusPlugin::new().0,
+            ProxyMirrorPlugin::new().0,
+            ProxyRewritePlugin::new().0,
+            PublicApiPlugin::new().0,
+            RealIpPlugin::new().0,
+            RedirectPlugin::new().0,
+            RefererRestrictionPlugin::new().0,
+            RequestIdPlugin::new().0,
+            RequestValidationPlugin::new().0,
+            ResponseRewritePlugin::new().0,
+            RouteBasicAuthPlugin::new().0,
+            RouteHmacAuthPlugin::new().0,
+            RouteJwtAuthPlugin::new().0,
+            RouteKeyAuthPlugin::new().0,
+            RouteLdapAuthPlugin::new().0,
+            ServerlessPostFunctionPlugin::new().0,
+            ServerlessPreFunctionPlugin::new().0,
+            SkywalkingPlugin::new().0,
+            SyslogPlugin::new().0,
+            TcpLoggerPlugin::new().0,
+            TrafficSplitPlugin::new().0,
+            UaRestrictionPlugin::new().0,
+            UdpLoggerPlugin::new().0,
+            UriBlockerPlugin::new().0,
+            WorkflowPlugin::new().0,
+            ZipkinPlugin::new().0,
+        ])
+    }
+
+    pub fn from_definitions(definitions: Vec<PluginDefinition>) -> Self {
+        Self { definitions }
+    }
+
+    pub fn definitions(&self) -> &[PluginDefinition] {
+        &self.definitions
+    }
+
+    pub fn into_definitions(self) -> Vec<PluginDefinition> {
+        self.definitions
+    }
+
+    // The same name can have different definitions per entity, e.g. the auth plugins
+    pub fn get(&self, name: &str, entity: &PluginEntities) -> Option<&PluginDefinition> {
+        self.definitions
+            .iter()
+            .find(|d| d.name == name && d.entities.contains(entity))
+    }
+
+    pub fn for_entity(&self, entity: &PluginEntities) -> Vec<&PluginDefinition> {
+        self.definitions
+            .iter()
+            .filter(|d| d.entities.contains(entity))
+            .collect()
+    }
+
+    // Drops the plugins and options that the given APISIX version doesn't support
+    pub fn for_version(&self, version: &str) -> Self {
+        let definitions = self
+            .definitions
+            .iter()
+            .filter(|d| is_version_available(&d.version_range, version))
+            .map(|d| PluginDefinition {
+                options: d
+                    .options
+                    .iter()
+                    .filter(|o| is_version_available(&o.version_range, version))
+                    .cloned()
+                    .collect(),
+                ..d.clone()
+            })
+            .collect();
+
+        Self::from_definitions(definitions)
+    }
+
+    /// Definitions listed by the clients, generated from the schema of APISIX when it is available
+    /// and filtered by its version otherwise
+    pub fn client_definitions(
+        &self,
+        schema: Option<&ApisixSchema>,
+        version: Option<&str>,
+    ) -> Vec<PluginDefinition> {
+        let registry = match version {
+            Some(version) => self.for_version(version),
+            None => Self::from_definitions(self.definitions.clone()),
+        };
+        let mut definitions = match schema {
+            Some(schema) => schema.plugin_definitions(registry.definitions()),
+            None => registry.into_definitions(),
+        };
+
+        // `_meta` is accepted by every plugin, listing it keeps it when editing the plugins
+        for definition in definitions.iter_mut() {
+            if !definition.options.iter().any(|o| o.name == META_KEY) {
+                definition.options.push(meta_option());
+            }
+        }
+
+        definitions
+    }
+
+    // Definitions with the same name and entities replace the existing ones
+    pub fn extend(&mut self, definitions: impl IntoIterator<Item = PluginDefinition>) {
+        for definition in definitions {
+            self.definitions
+                .retain(|d| d.name != definition.name || d.entities != definition.entities);
+            self.definitions.push(definition);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn option_names(definitions: &[PluginDefinition], name: &str) -> Vec<String> {
+        definitions
+            .iter()
+            .find(|d| d.name == name)
+            .map(|d| d.options.iter().map(|o| o.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_for_version() {
+        let registry = PluginRegistry::new();
+
+        let old = registry.for_version("3.4.0");
+        let options = option_names(old.definitions(), "ua-restriction");
+        assert!(options.contains(&"bypass_missing".to_string()));
+        assert!(!options.contains(&"allowlist".to_string()));
+        assert!(old.definitions().iter().all(|d| d.name != "chaitin-waf"));
+
+        let current = registry.for_version("3.11.0");
+        assert!(option_names(current.definitions(), "ua-restriction")
+            .contains(&"allowlist".to_string()));
+        assert!(option_names(current.definitions(), "limit-conn").contains(&"policy".to_string()));
+
+        let definitions = registry.client_definitions(None, Some("3.10.0"));
+        assert!(!option_names(&definitions, "limit-conn").contains(&"policy".to_string()));
+        assert!(option_names(&definitions, "limit-count").contains(&"policy".to_string()));
+    }
+
+    #[test]
+    fn test_client_definitions() {
+        let registry = PluginRegistry::new();
+
+        let definitions = registry.client_definitions(None, None);
+        assert_eq!(definitions.len(), registry.definitions().len());
+        assert!(definitions
+            .iter()
+            .all(|d| d.options.iter().any(|o| o.name == META_KEY)));
+
+        let schema: ApisixSchema = serde_json::from_value(json!({
+            "main": {},
+            "plugins": {
+                "limit-count": {
+                    "schema": {
+                        "type": "object",
+                        "properties": { "count": { "type": "integer" } },
+                        "required": ["count"],
+                    },
+                },
+            },
+        }))
+        .unwrap();
+
+        let definitions = registry.client_definitions(Some(&schema), None);
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].name, "limit-count");
+        // The description of the option comes from the hand-written definition
+        let count = definitions[0]
+            .options
+            .iter()
+            .find(|o| o.name == "count")
+            .unwrap();
+        assert!(!count.description.is_empty());
+        assert!(definitions[0].options.iter().any(|o| o.name == META_KEY));
+    }
+}
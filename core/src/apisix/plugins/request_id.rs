@@ -0,0 +1,73 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RequestIdPlugin(pub PluginDefinition);}
+
+impl RequestIdPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "request-id".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("X-Request-Id".to_string()),
+                    description: "Header name for the unique request ID.".to_string(),
+                    name: "header_name".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("true".to_string()),
+                    description:
+                        "When set to true, adds the unique request ID in the response header."
+                            .to_string(),
+                    name: "include_in_response".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("uuid".to_string()),
+                    description: "Algorithm to use for generating the unique request ID."
+                        .to_string(),
+                    name: "algorithm".to_string(),
+                    property_type: PropertyType::Enum(vec![
+                        "uuid".to_string(),
+                        "nanoid".to_string(),
+                        "range_id".to_string(),
+                    ]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Configuration used when the algorithm is range_id.".to_string(),
+                    name: "range_id".to_string(),
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            default_value: Some(
+                                "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789"
+                                    .to_string(),
+                            ),
+                            description: "The character set for the ID.".to_string(),
+                            name: "char_set".to_string(),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            default_value: Some("16".to_string()),
+                            description: "The length of the ID.".to_string(),
+                            name: "length".to_string(),
+                            property_type: PropertyType::Number,
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
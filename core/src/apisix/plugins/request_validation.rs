@@ -0,0 +1,49 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct RequestValidationPlugin(pub PluginDefinition);}
+
+impl RequestValidationPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "request-validation".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "JSON schema used to validate the request headers.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["body_schema".to_string()]),
+                    name: "header_schema".to_string(),
+                    property_type: PropertyType::Map(Box::new(PropertyType::JSON)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "JSON schema used to validate the request body.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["header_schema".to_string()]),
+                    name: "body_schema".to_string(),
+                    property_type: PropertyType::Map(Box::new(PropertyType::JSON)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("400".to_string()),
+                    description: "Status code to return when the request is rejected.".to_string(),
+                    name: "rejected_code".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Message to return when the request is rejected.".to_string(),
+                    name: "rejected_msg".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use super::common::{PluginDefinition, PluginEntities, PluginOption};
-use crate::{apisix::base::PropertyType, macros::derive_common_default};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required},
+    macros::derive_common_default,
+};
 
 derive_common_default! {
-pub struct ResponseRewritePlugin(PluginDefinition);}
+pub struct ResponseRewritePlugin(pub PluginDefinition);}
 
 impl ResponseRewritePlugin {
     pub fn new() -> Self {
@@ -28,8 +31,28 @@ impl ResponseRewritePlugin {
                     ..Default::default()
                 },
                 PluginOption {
+                    description: "Headers of the response. The operations are executed in the order add, remove and set.".to_string(),
                     name: "headers".to_string(),
-                    property_type: PropertyType::JSON,
+                    property_type: PropertyType::Object(vec![
+                        PropertyField {
+                            description: "Appends the new headers, in the format [\"name: value\", ...]. The value supports Nginx variables.".to_string(),
+                            name: "add".to_string(),
+                            property_type: PropertyType::List(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Overwrites the headers. If the header does not exist, it will be added. The value supports Nginx variables.".to_string(),
+                            name: "set".to_string(),
+                            property_type: PropertyType::Map(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                        PropertyField {
+                            description: "Removes the headers.".to_string(),
+                            name: "remove".to_string(),
+                            property_type: PropertyType::List(Box::new(PropertyType::String)),
+                            ..Default::default()
+                        },
+                    ]),
                     ..Default::default()
                 },
                 PluginOption {
@@ -44,11 +67,41 @@ impl ResponseRewritePlugin {
                     description: "List of filters that modify the response body by replacing one specified string with another.".to_string(),
                     name: "filters".to_string(),
                     property_type: PropertyType::List(
-                        Box::new(PropertyType::JSON),
+                        Box::new(PropertyType::Object(vec![
+                            PropertyField {
+                                description: "Regex pattern to match on the response body.".to_string(),
+                                is_required: Required::True,
+                                name: "regex".to_string(),
+                                ..Default::default()
+                            },
+                            PropertyField {
+                                default_value: Some("once".to_string()),
+                                description: "Scope of substitution. once substitutes the first match of the regex pattern and global substitutes all the matches.".to_string(),
+                                name: "scope".to_string(),
+                                property_type: PropertyType::Enum(vec![
+                                    "once".to_string(),
+                                    "global".to_string(),
+                                ]),
+                                ..Default::default()
+                            },
+                            PropertyField {
+                                description: "Content to substitute with.".to_string(),
+                                is_required: Required::True,
+                                name: "replace".to_string(),
+                                ..Default::default()
+                            },
+                            PropertyField {
+                                default_value: Some("jo".to_string()),
+                                description: "Regex options to control how the match operation should be performed.".to_string(),
+                                name: "options".to_string(),
+                                ..Default::default()
+                            },
+                        ])),
                     ),
                     ..Default::default()
                 }
             ],
+            ..Default::default()
         })
     }
 }
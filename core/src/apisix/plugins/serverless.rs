@@ -0,0 +1,58 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ServerlessPreFunctionPlugin(pub PluginDefinition);}
+
+impl ServerlessPreFunctionPlugin {
+    pub fn new() -> Self {
+        Self(serverless_definition("serverless-pre-function"))
+    }
+}
+
+derive_common_default! {
+pub struct ServerlessPostFunctionPlugin(pub PluginDefinition);}
+
+impl ServerlessPostFunctionPlugin {
+    pub fn new() -> Self {
+        Self(serverless_definition("serverless-post-function"))
+    }
+}
+
+// Both plugins share the options, they only differ in their priority
+fn serverless_definition(name: &str) -> PluginDefinition {
+    PluginDefinition {
+        name: name.to_string(),
+        entities: [PluginEntities::Route, PluginEntities::Service]
+            .iter()
+            .cloned()
+            .collect(),
+        options: vec![
+            PluginOption {
+                default_value: Some("access".to_string()),
+                description: "Phase before or after which the serverless function is executed.".to_string(),
+                name: "phase".to_string(),
+                property_type: PropertyType::Enum(vec![
+                    "rewrite".to_string(),
+                    "access".to_string(),
+                    "header_filter".to_string(),
+                    "body_filter".to_string(),
+                    "log".to_string(),
+                    "before_proxy".to_string(),
+                ]),
+                ..Default::default()
+            },
+            PluginOption {
+                description: "List of functions that are executed sequentially, each one has to return a Lua function like: return function(conf, ctx) ... end".to_string(),
+                is_required: Required::True,
+                name: "functions".to_string(),
+                property_type: PropertyType::List(Box::new(PropertyType::Code("lua".to_string()))),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
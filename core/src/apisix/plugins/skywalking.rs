@@ -0,0 +1,25 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{apisix::base::PropertyType, macros::derive_common_default};
+
+derive_common_default! {
+pub struct SkywalkingPlugin(pub PluginDefinition);}
+
+impl SkywalkingPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "skywalking".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![PluginOption {
+                default_value: Some("1".to_string()),
+                description: "How often to sample the requests, between 0.00001 and 1. Setting to 1 will sample all requests. The SkyWalking OAP server is configured in config.yaml.".to_string(),
+                name: "sample_ratio".to_string(),
+                property_type: PropertyType::Number,
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, log_format_option, PluginDefinition,
+    PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct SyslogPlugin(pub PluginDefinition);}
+
+impl SyslogPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "syslog".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "IP address or the hostname of the Syslog server.".to_string(),
+                    is_required: Required::True,
+                    name: "host".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Target port of the Syslog server.".to_string(),
+                    is_required: Required::True,
+                    name: "port".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3000".to_string()),
+                    description: "Timeout in milliseconds for the upstream to send data.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true performs TLS verification.".to_string(),
+                    name: "tls".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("4096".to_string()),
+                    description: "Maximum size of the buffer (KB) and the current message (KB) before it is flushed and written to the server.".to_string(),
+                    name: "flush_limit".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1048576".to_string()),
+                    description: "Maximum size of the buffer (KB) and the current message (KB) before the current message is dropped because of the size limit.".to_string(),
+                    name: "drop_limit".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("tcp".to_string()),
+                    description: "Transport layer protocol to use.".to_string(),
+                    name: "sock_type".to_string(),
+                    property_type: PropertyType::Enum(vec!["tcp".to_string(), "udp".to_string()]),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("5".to_string()),
+                    description: "Keep-alive pool size used by sock:keepalive.".to_string(),
+                    name: "pool_size".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                log_format_option(),
+                include_req_body_option(),
+            ]
+            .into_iter()
+            .chain(batch_processor_options("sys logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
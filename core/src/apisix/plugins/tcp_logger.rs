@@ -0,0 +1,64 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, log_format_option, PluginDefinition,
+    PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct TcpLoggerPlugin(pub PluginDefinition);}
+
+impl TcpLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "tcp-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "IP address or the hostname of the TCP server.".to_string(),
+                    is_required: Required::True,
+                    name: "host".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Target upstream port.".to_string(),
+                    is_required: Required::True,
+                    name: "port".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("1000".to_string()),
+                    description: "Timeout in milliseconds for the upstream to send data."
+                        .to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                log_format_option(),
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true performs SSL verification.".to_string(),
+                    name: "tls".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "TLS options.".to_string(),
+                    name: "tls_options".to_string(),
+                    ..Default::default()
+                },
+                include_req_body_option(),
+            ]
+            .into_iter()
+            .chain(batch_processor_options("tcp logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
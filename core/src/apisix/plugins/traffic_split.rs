@@ -0,0 +1,97 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::{
+        base::{PropertyField, PropertyType, Required},
+        validation::FieldError,
+    },
+    macros::derive_common_default,
+};
+use serde_json::Value;
+
+derive_common_default! {
+pub struct TrafficSplitPlugin(pub PluginDefinition);}
+
+impl TrafficSplitPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "traffic-split".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![PluginOption {
+                description: "List of rules to match the requests and the Upstreams to split them between.".to_string(),
+                is_required: Required::True,
+                name: "rules".to_string(),
+                property_type: PropertyType::List(Box::new(PropertyType::Object(vec![
+                    PropertyField {
+                        description: "Match rules with lists of vars. If empty, the weighted_upstreams are always used.".to_string(),
+                        name: "match".to_string(),
+                        property_type: PropertyType::JSON,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "List of Upstreams with their weights. An item with only the weight uses the Upstream of the Route.".to_string(),
+                        is_required: Required::True,
+                        name: "weighted_upstreams".to_string(),
+                        property_type: PropertyType::List(Box::new(PropertyType::Object(vec![
+                            PropertyField {
+                                description: "Id of an existing Upstream.".to_string(),
+                                name: "upstream_id".to_string(),
+                                ..Default::default()
+                            },
+                            PropertyField {
+                                description: "Inline Upstream configuration, only used when there is no upstream_id.".to_string(),
+                                name: "upstream".to_string(),
+                                property_type: PropertyType::JSON,
+                                ..Default::default()
+                            },
+                            PropertyField {
+                                default_value: Some("1".to_string()),
+                                description: "Weight of the Upstream used to split the traffic.".to_string(),
+                                name: "weight".to_string(),
+                                property_type: PropertyType::Number,
+                                ..Default::default()
+                            },
+                        ]))),
+                        ..Default::default()
+                    },
+                ]))),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+
+    /// Reports the weighted upstreams that reference an `upstream_id` not in `upstream_ids`
+    pub fn validate_upstream_ids(
+        config: &Value,
+        upstream_ids: &[String],
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        let rules = config.get("rules").and_then(|r| r.as_array());
+
+        for (rule_idx, rule) in rules.into_iter().flatten().enumerate() {
+            let weighted = rule.get("weighted_upstreams").and_then(|w| w.as_array());
+
+            for (idx, weighted_upstream) in weighted.into_iter().flatten().enumerate() {
+                let upstream_id = match weighted_upstream.get("upstream_id") {
+                    Some(Value::String(id)) => id.clone(),
+                    Some(Value::Number(id)) => id.to_string(),
+                    _ => continue,
+                };
+
+                if !upstream_ids.contains(&upstream_id) {
+                    let field = format!(
+                        "{}.rules[{}].weighted_upstreams[{}].upstream_id",
+                        path, rule_idx, idx
+                    );
+                    let message = format!("upstream {} does not exist", upstream_id);
+
+                    errors.push(FieldError::new(&field, &message));
+                }
+            }
+        }
+    }
+}
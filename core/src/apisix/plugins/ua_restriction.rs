@@ -0,0 +1,55 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required, VersionRange},
+    macros::derive_common_default,
+};
+
+// Older versions name the lists `whitelist` and `blacklist`
+const LISTS_VERSION: &str = "3.5.0";
+
+derive_common_default! {
+pub struct UaRestrictionPlugin(pub PluginDefinition);}
+
+impl UaRestrictionPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "ua-restriction".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    default_value: Some("false".to_string()),
+                    description: "When set to true, bypasses the check when the User-Agent header is missing.".to_string(),
+                    name: "bypass_missing".to_string(),
+                    property_type: PropertyType::Boolean,
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "List of allowed User-Agent headers, can be regular expressions.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["denylist".to_string()]),
+                    name: "allowlist".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    version_range: Some(VersionRange::since(LISTS_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "List of denied User-Agent headers, can be regular expressions.".to_string(),
+                    is_required: Required::TrueIfOtherMissing(vec!["allowlist".to_string()]),
+                    name: "denylist".to_string(),
+                    property_type: PropertyType::List(Box::new(PropertyType::String)),
+                    version_range: Some(VersionRange::since(LISTS_VERSION)),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("Not allowed".to_string()),
+                    description: "Message returned when the User-Agent is not allowed access.".to_string(),
+                    name: "message".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use super::common::{
+    batch_processor_options, include_req_body_option, log_format_option, PluginDefinition,
+    PluginEntities, PluginOption,
+};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct UdpLoggerPlugin(pub PluginDefinition);}
+
+impl UdpLoggerPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "udp-logger".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "IP address or the hostname of the UDP server.".to_string(),
+                    is_required: Required::True,
+                    name: "host".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "Target upstream port.".to_string(),
+                    is_required: Required::True,
+                    name: "port".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("3".to_string()),
+                    description: "Timeout in seconds for the upstream to send data.".to_string(),
+                    name: "timeout".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                log_format_option(),
+                include_req_body_option(),
+            ]
+            .into_iter()
+            .chain(batch_processor_options("udp logger"))
+            .collect(),
+            ..Default::default()
+        })
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 };
 
 derive_common_default! {
-pub struct UriBlockerPlugin(PluginDefinition);}
+pub struct UriBlockerPlugin(pub PluginDefinition);}
 
 impl UriBlockerPlugin {
     pub fn new() -> Self {
@@ -43,6 +43,7 @@ impl UriBlockerPlugin {
                     ..Default::default()
                 }
             ],
+            ..Default::default()
         })
     }
 }
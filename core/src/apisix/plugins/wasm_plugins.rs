@@ -0,0 +1,45 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::apisix::base::{PropertyType, Required};
+use serde_json::Value;
+
+// Proxy-wasm plugins are declared in the `wasm.plugins` list of APISIX's `config.yaml`, they all
+// receive their configuration in a single `conf` field
+pub fn wasm_plugin_definitions(config: &Value) -> Vec<PluginDefinition> {
+    let Some(plugins) = config.pointer("/wasm/plugins").and_then(Value::as_array) else {
+        return vec![];
+    };
+
+    plugins
+        .iter()
+        .filter_map(|plugin| {
+            let name = plugin.get("name")?.as_str()?;
+            let file = plugin.get("file").and_then(Value::as_str).unwrap_or("");
+
+            Some(PluginDefinition {
+                name: name.to_string(),
+                entities: [
+                    PluginEntities::Route,
+                    PluginEntities::Service,
+                    PluginEntities::ConsumerGroup,
+                    PluginEntities::Consumer,
+                ]
+                .iter()
+                .cloned()
+                .collect(),
+                options: vec![PluginOption {
+                    description: format!(
+                        "Configuration passed to the proxy-wasm plugin loaded from {}, either a string or a JSON object.",
+                        file
+                    ),
+                    is_required: Required::True,
+                    name: "conf".to_string(),
+                    property_type: PropertyType::JSON,
+                    // The configuration is opaque, it can include credentials
+                    sensitive: true,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+        })
+        .collect()
+}
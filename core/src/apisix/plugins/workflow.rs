@@ -0,0 +1,42 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyField, PropertyType, Required, VersionRange},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct WorkflowPlugin(pub PluginDefinition);}
+
+impl WorkflowPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "workflow".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![PluginOption {
+                description: "List of rules, the actions of the first rule whose case matches are executed.".to_string(),
+                is_required: Required::True,
+                name: "rules".to_string(),
+                property_type: PropertyType::List(Box::new(PropertyType::Object(vec![
+                    PropertyField {
+                        description: "List of vars expressions, with the same format as the vars of a Route. If empty, the actions are always executed.".to_string(),
+                        name: "case".to_string(),
+                        property_type: PropertyType::JSON,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "List of actions, each one an array of the action name and its config, like [\"return\", {\"code\": 403}] or [\"limit-count\", {...}].".to_string(),
+                        is_required: Required::True,
+                        name: "actions".to_string(),
+                        property_type: PropertyType::JSON,
+                        ..Default::default()
+                    },
+                ]))),
+                ..Default::default()
+            }],
+            version_range: Some(VersionRange::since("2.15.0")),
+        })
+    }
+}
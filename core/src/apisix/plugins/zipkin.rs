@@ -0,0 +1,54 @@
+use super::common::{PluginDefinition, PluginEntities, PluginOption};
+use crate::{
+    apisix::base::{PropertyType, Required},
+    macros::derive_common_default,
+};
+
+derive_common_default! {
+pub struct ZipkinPlugin(pub PluginDefinition);}
+
+impl ZipkinPlugin {
+    pub fn new() -> Self {
+        Self(PluginDefinition {
+            name: "zipkin".to_string(),
+            entities: [PluginEntities::Route, PluginEntities::Service]
+                .iter()
+                .cloned()
+                .collect(),
+            options: vec![
+                PluginOption {
+                    description: "Zipkin HTTP endpoint. For example, http://127.0.0.1:9411/api/v2/spans.".to_string(),
+                    is_required: Required::True,
+                    name: "endpoint".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "How often to sample the requests, between 0.00001 and 1. Setting to 1 will sample all requests.".to_string(),
+                    is_required: Required::True,
+                    name: "sample_ratio".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("APISIX".to_string()),
+                    description: "Service name for the Zipkin reporter to be displayed in Zipkin.".to_string(),
+                    name: "service_name".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    description: "IPv4 address for the Zipkin reporter. For example, you can set it to your external IP address.".to_string(),
+                    name: "server_addr".to_string(),
+                    ..Default::default()
+                },
+                PluginOption {
+                    default_value: Some("2".to_string()),
+                    description: "Version of the span type.".to_string(),
+                    name: "span_version".to_string(),
+                    property_type: PropertyType::Number,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,621 @@
+use super::{
+    common::prelude::*, consumer_groups::ConsumerGroup, consumers::Consumer, routes::Route,
+    services::Service, upstreams::Upstream,
+};
+use crate::{
+    macros::derive_common,
+    proxy::{ProxyFetchMethod, ProxyFetchOpts},
+};
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use ts_rs::TS;
+
+// The variants are in the order in which they are applied, so the references of an entity
+// already exist in the target when it is created
+derive_common! {
+#[derive(TS, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[ts(export)]
+pub enum PromotionEntity {
+    Ssl,
+    Upstream,
+    Service,
+    PluginConfig,
+    ConsumerGroup,
+    Consumer,
+    // Consumer credentials of APISIX 3.7 and later, the id is `{username}/credentials/{id}`
+    Credential,
+    Route,
+}}
+
+impl PromotionEntity {
+    // Entities listed with their prefix, the credentials are listed for each consumer
+    pub const ALL: [Self; 7] = [
+        Self::Ssl,
+        Self::Upstream,
+        Self::Service,
+        Self::PluginConfig,
+        Self::ConsumerGroup,
+        Self::Consumer,
+        Self::Route,
+    ];
+
+    pub fn api_prefix(&self) -> &'static str {
+        match self {
+            Self::Ssl => "/ssls",
+            Self::Upstream => Upstream::API_PREFIX,
+            Self::Service => Service::API_PREFIX,
+            Self::PluginConfig => "/plugin_configs",
+            Self::ConsumerGroup => ConsumerGroup::API_PREFIX,
+            Self::Consumer | Self::Credential => Consumer::API_PREFIX,
+            Self::Route => Route::API_PREFIX,
+        }
+    }
+
+    pub fn id_name(&self) -> &'static str {
+        match self {
+            Self::Consumer => Consumer::ID_NAME,
+            _ => "id",
+        }
+    }
+
+    pub fn get_all(&self) -> Result<ProxyFetchOpts, String> {
+        ProxyFetchOpts::get(self.api_prefix())
+    }
+}
+
+derive_common! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+pub enum PromotionAction {
+    Create,
+    Update,
+    Unchanged,
+}}
+
+derive_common! {
+#[derive(TS)]
+#[ts(export)]
+pub struct PromotionDiff {
+    // The keys can include dots, like the upstream nodes
+    pub path: Vec<String>,
+    #[ts(type = "unknown")]
+    pub source: Option<Value>,
+    #[ts(type = "unknown")]
+    pub target: Option<Value>,
+}}
+
+derive_common! {
+#[derive(TS)]
+#[ts(export)]
+pub struct PromotionChange {
+    pub action: PromotionAction,
+    // Fields that differ from the target, empty when the entity is created
+    pub diff: Vec<PromotionDiff>,
+    pub entity: PromotionEntity,
+    pub id: String,
+    // References to secrets and env vars, which aren't promoted and must exist in the target
+    pub unresolved: Vec<String>,
+    #[ts(type = "unknown")]
+    pub value: Value,
+}}
+
+impl PromotionChange {
+    pub fn apply(&self) -> Result<ProxyFetchOpts, String> {
+        let mut value = self.value.clone();
+
+        // Consumers are upserted by the username in the body, the rest by the id in the uri
+        let uri = match self.entity {
+            PromotionEntity::Consumer => self.entity.api_prefix().to_string(),
+            _ => {
+                if let Some(fields) = value.as_object_mut() {
+                    fields.remove("id");
+                }
+
+                format!("{}/{}", self.entity.api_prefix(), self.id)
+            }
+        };
+
+        Ok(ProxyFetchOpts {
+            uri,
+            method: ProxyFetchMethod::PUT,
+            data: Some(serde_json::to_string(&value).map_err(|e| e.to_string())?),
+        })
+    }
+}
+
+// Entities of a cluster, filled from the list responses of the admin API
+#[derive(Default)]
+pub struct ClusterItems(HashMap<PromotionEntity, Vec<Value>>);
+
+impl ClusterItems {
+    pub fn insert_list_response(&mut self, entity: PromotionEntity, response: &Value) {
+        let items = response
+            .get("list")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|item| item.get("value").unwrap_or(item).clone())
+            .collect();
+
+        self.0.insert(entity, items);
+    }
+
+    /// Adds the list response of the credentials of a consumer, with the username in their ids
+    pub fn insert_credentials(&mut self, username: &str, response: &Value) {
+        let mut items = ClusterItems::default();
+        items.insert_list_response(PromotionEntity::Credential, response);
+
+        let credentials = items
+            .0
+            .remove(&PromotionEntity::Credential)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|mut item| {
+                let id = id_field(&item, "id")?;
+                item["id"] = Value::String(format!("{username}/credentials/{id}"));
+
+                Some(item)
+            });
+
+        self.0
+            .entry(PromotionEntity::Credential)
+            .or_default()
+            .extend(credentials);
+    }
+
+    pub fn list(&self, entity: &PromotionEntity) -> &[Value] {
+        self.0.get(entity).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    fn credential_ids(&self, username: &str) -> Vec<String> {
+        let prefix = format!("{username}/credentials/");
+
+        self.0
+            .get(&PromotionEntity::Credential)
+            .into_iter()
+            .flatten()
+            .filter_map(|item| id_field(item, "id"))
+            .filter(|id| id.starts_with(&prefix))
+            .collect()
+    }
+
+    pub fn find(&self, entity: &PromotionEntity, id: &str) -> Option<&Value> {
+        self.0
+            .get(entity)?
+            .iter()
+            .find(|item| id_field(item, entity.id_name()).as_deref() == Some(id))
+    }
+}
+
+// Ids can be numbers or strings in APISIX
+fn id_field(value: &Value, key: &str) -> Option<String> {
+    match value.get(key)? {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn id_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|id| match id {
+            Value::String(id) => Some(id.clone()),
+            Value::Number(id) => Some(id.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn plugin_dependencies(plugins: &Map<String, Value>) -> Vec<(PromotionEntity, String)> {
+    let mut dependencies = vec![];
+
+    if let Some(rules) = plugins
+        .get("traffic-split")
+        .and_then(|p| p.get("rules"))
+        .and_then(Value::as_array)
+    {
+        for rule in rules {
+            let weighted = rule.get("weighted_upstreams").and_then(Value::as_array);
+
+            for weighted_upstream in weighted.into_iter().flatten() {
+                if let Some(id) = id_field(weighted_upstream, "upstream_id") {
+                    dependencies.push((PromotionEntity::Upstream, id));
+                }
+            }
+        }
+    }
+
+    // Only the allowed objects are needed, denying a missing one has the same effect
+    if let Some(restriction) = plugins.get("consumer-restriction") {
+        let restriction_type = restriction
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("consumer_name");
+        let mut allowed = id_list(restriction.get("whitelist"));
+
+        if let Some(by_methods) = restriction
+            .get("allowed_by_methods")
+            .and_then(Value::as_array)
+        {
+            allowed.extend(by_methods.iter().filter_map(|m| id_field(m, "user")));
+        }
+
+        let entity = match restriction_type {
+            "consumer_name" => Some(PromotionEntity::Consumer),
+            "consumer_group_id" => Some(PromotionEntity::ConsumerGroup),
+            "service_id" => Some(PromotionEntity::Service),
+            _ => None,
+        };
+
+        if let Some(entity) = entity {
+            dependencies.extend(allowed.into_iter().map(|id| (entity.clone(), id)));
+        }
+    }
+
+    dependencies
+}
+
+fn dependencies(value: &Value) -> Vec<(PromotionEntity, String)> {
+    let mut dependencies = vec![];
+    let references = [
+        ("upstream_id", PromotionEntity::Upstream),
+        ("service_id", PromotionEntity::Service),
+        ("plugin_config_id", PromotionEntity::PluginConfig),
+        ("group_id", PromotionEntity::ConsumerGroup),
+    ];
+
+    for (key, entity) in references {
+        if let Some(id) = id_field(value, key) {
+            dependencies.push((entity, id));
+        }
+    }
+
+    // The client certificate of the upstreams with mTLS
+    if let Some(id) = value
+        .get("tls")
+        .and_then(|tls| id_field(tls, "client_cert_id"))
+    {
+        dependencies.push((PromotionEntity::Ssl, id));
+    }
+
+    if let Some(plugins) = value.get("plugins").and_then(Value::as_object) {
+        dependencies.extend(plugin_dependencies(plugins));
+    }
+
+    dependencies
+}
+
+fn references(value: &Value, found: &mut BTreeSet<String>) {
+    match value {
+        Value::String(s) if s.starts_with("$secret://") || s.starts_with("$env://") => {
+            found.insert(s.clone());
+        }
+        Value::Array(items) => items.iter().for_each(|v| references(v, found)),
+        Value::Object(map) => map.values().for_each(|v| references(v, found)),
+        _ => {}
+    }
+}
+
+/// Returns the entity and everything that it references, in the order to apply them
+pub fn resolve_dependencies(
+    entity: &PromotionEntity,
+    id: &str,
+    source: &ClusterItems,
+) -> Result<Vec<(PromotionEntity, String, Value)>, String> {
+    let mut visited = BTreeSet::new();
+    let mut pending = vec![(entity.clone(), id.to_string())];
+    let mut resolved = vec![];
+
+    while let Some((entity, id)) = pending.pop() {
+        if !visited.insert((entity.clone(), id.clone())) {
+            continue;
+        }
+
+        let value = source
+            .find(&entity, &id)
+            .ok_or_else(|| format!("{:?} {} not found in the source cluster", entity, id))?;
+
+        pending.extend(dependencies(value));
+
+        // The credentials can't exist without their consumer, so they are promoted with it
+        if entity == PromotionEntity::Consumer {
+            let credentials = source.credential_ids(&id).into_iter();
+            pending.extend(credentials.map(|id| (PromotionEntity::Credential, id)));
+        }
+
+        resolved.push((entity, id, value.clone()));
+    }
+
+    resolved.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+    Ok(resolved)
+}
+
+// Set by APISIX when the entity is written, they always differ between clusters
+fn without_timestamps(value: &Value) -> Value {
+    let mut value = value.clone();
+
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("create_time");
+        fields.remove("update_time");
+    }
+
+    value
+}
+
+fn diff_values(
+    path: &[String],
+    source: Option<&Value>,
+    target: Option<&Value>,
+) -> Vec<PromotionDiff> {
+    match (source, target) {
+        (Some(Value::Object(source)), Some(Value::Object(target))) => {
+            let keys: BTreeSet<&String> = source.keys().chain(target.keys()).collect();
+
+            keys.into_iter()
+                .flat_map(|key| {
+                    let mut path = path.to_vec();
+                    path.push(key.clone());
+
+                    diff_values(&path, source.get(key), target.get(key))
+                })
+                .collect()
+        }
+        _ if source == target => vec![],
+        _ => vec![PromotionDiff {
+            path: path.to_vec(),
+            source: source.cloned(),
+            target: target.cloned(),
+        }],
+    }
+}
+
+/// Compares the entity and its dependencies with the target cluster, the changes are listed in
+/// the order to apply them
+pub fn plan_promotion(
+    entity: &PromotionEntity,
+    id: &str,
+    source: &ClusterItems,
+    target: &ClusterItems,
+) -> Result<Vec<PromotionChange>, String> {
+    let changes = resolve_dependencies(entity, id, source)?
+        .into_iter()
+        .map(|(entity, id, value)| {
+            let value = without_timestamps(&value);
+            let (action, diff) = match target.find(&entity, &id) {
+                None => (PromotionAction::Create, vec![]),
+                Some(existing) => {
+                    let diff = diff_values(&[], Some(&value), Some(&without_timestamps(existing)));
+
+                    if diff.is_empty() {
+                        (PromotionAction::Unchanged, diff)
+                    } else {
+                        (PromotionAction::Update, diff)
+                    }
+                }
+            };
+            let mut unresolved = BTreeSet::new();
+            references(&value, &mut unresolved);
+
+            PromotionChange {
+                action,
+                diff,
+                entity,
+                id,
+                unresolved: unresolved.into_iter().collect(),
+                value,
+            }
+        })
+        .collect();
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn items(lists: &[(PromotionEntity, Value)]) -> ClusterItems {
+        let mut items = ClusterItems::default();
+
+        for (entity, list) in lists {
+            items.insert_list_response(
+                entity.clone(),
+                &json!({ "list": list.as_array().unwrap().iter().map(|v| json!({ "value": v })).collect::<Vec<_>>() }),
+            );
+        }
+
+        items
+    }
+
+    #[test]
+    fn test_plan_promotion() {
+        let source = items(&[
+            (
+                PromotionEntity::Route,
+                json!([{
+                    "id": "r1",
+                    "service_id": "s1",
+                    "plugin_config_id": 3,
+                    "update_time": 1,
+                    "plugins": {
+                        "consumer-restriction": { "whitelist": ["alice"] },
+                        "traffic-split": {
+                            "rules": [{ "weighted_upstreams": [{ "upstream_id": "u2", "weight": 1 }] }],
+                        },
+                    },
+                }]),
+            ),
+            (
+                PromotionEntity::Service,
+                json!([{ "id": "s1", "upstream_id": "u1" }]),
+            ),
+            (
+                PromotionEntity::Upstream,
+                json!([{ "id": "u1", "nodes": { "a:80": 1 } }, { "id": "u2", "nodes": { "b:80": 1 } }]),
+            ),
+            (
+                PromotionEntity::PluginConfig,
+                json!([{ "id": "3", "plugins": {} }]),
+            ),
+            (
+                PromotionEntity::Consumer,
+                json!([{ "username": "alice", "group_id": "g" }]),
+            ),
+            (
+                PromotionEntity::ConsumerGroup,
+                json!([{ "id": "g", "plugins": {} }]),
+            ),
+        ]);
+        let target = items(&[(
+            PromotionEntity::Upstream,
+            json!([
+                { "id": "u1", "nodes": { "a:80": 2 }, "create_time": 5 },
+                { "id": "u2", "nodes": { "b:80": 1 } },
+            ]),
+        )]);
+
+        let changes = plan_promotion(&PromotionEntity::Route, "r1", &source, &target).unwrap();
+        assert_eq!(changes.len(), 7);
+        assert_eq!(changes[0].action, PromotionAction::Update);
+        assert_eq!(changes[1].action, PromotionAction::Unchanged);
+        assert_eq!(changes[6].entity, PromotionEntity::Route);
+
+        let opts = changes[6].apply().unwrap();
+        assert_eq!(opts.uri, "/routes/r1");
+        assert!(!opts.data.unwrap().contains("update_time"));
+
+        let consumer = changes
+            .iter()
+            .find(|c| c.entity == PromotionEntity::Consumer)
+            .unwrap();
+        assert_eq!(consumer.apply().unwrap().uri, "/consumers");
+
+        assert!(plan_promotion(&PromotionEntity::Route, "nope", &source, &target).is_err());
+    }
+
+    #[test]
+    fn test_consumer_credentials() {
+        let mut source = items(&[(
+            PromotionEntity::Consumer,
+            json!([{ "username": "jack" }, { "username": "jill" }]),
+        )]);
+        let credentials = |ids: &[&str]| {
+            let list: Vec<Value> = ids
+                .iter()
+                .map(
+                    |id| json!({ "value": { "id": id, "plugins": { "key-auth": { "key": id } } } }),
+                )
+                .collect();
+
+            json!({ "list": list })
+        };
+        source.insert_credentials("jack", &credentials(&["key-1", "key-2"]));
+        source.insert_credentials("jill", &credentials(&["key-1"]));
+        let mut target = ClusterItems::default();
+        target.insert_credentials("jack", &credentials(&["key-1"]));
+
+        let changes = plan_promotion(&PromotionEntity::Consumer, "jack", &source, &target).unwrap();
+        let ids: Vec<_> = changes
+            .iter()
+            .map(|c| (c.entity.clone(), c.id.as_str(), c.action.clone()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (PromotionEntity::Consumer, "jack", PromotionAction::Create),
+                (
+                    PromotionEntity::Credential,
+                    "jack/credentials/key-1",
+                    PromotionAction::Unchanged
+                ),
+                (
+                    PromotionEntity::Credential,
+                    "jack/credentials/key-2",
+                    PromotionAction::Create
+                ),
+            ]
+        );
+
+        let opts = changes[2].apply().unwrap();
+        assert_eq!(opts.uri, "/consumers/jack/credentials/key-2");
+        assert!(!opts.data.unwrap().contains("credentials"));
+    }
+
+    #[test]
+    fn test_diff_path() {
+        let source = items(&[(
+            PromotionEntity::Upstream,
+            json!([{ "id": "u1", "nodes": { "127.0.0.1:80": 1 } }]),
+        )]);
+        let target = items(&[(
+            PromotionEntity::Upstream,
+            json!([{ "id": "u1", "nodes": { "127.0.0.1:80": 2 } }]),
+        )]);
+
+        let changes = plan_promotion(&PromotionEntity::Upstream, "u1", &source, &target).unwrap();
+        assert_eq!(changes[0].diff[0].path, vec!["nodes", "127.0.0.1:80"]);
+    }
+
+    #[test]
+    fn test_references() {
+        let source = items(&[
+            (
+                PromotionEntity::Upstream,
+                json!([{
+                    "id": "u1",
+                    "nodes": { "a:80": 1 },
+                    "tls": { "client_cert_id": "c1" },
+                }]),
+            ),
+            (
+                PromotionEntity::Ssl,
+                json!([{ "id": "c1", "type": "client", "cert": "cert", "key": "$secret://vault/1/c1/key" }]),
+            ),
+            (
+                PromotionEntity::Route,
+                json!([{
+                    "id": "r1",
+                    "upstream_id": "u1",
+                    "plugins": { "key-auth": { "header": "$env://AUTH_HEADER" } },
+                }]),
+            ),
+        ]);
+
+        let changes = plan_promotion(
+            &PromotionEntity::Route,
+            "r1",
+            &source,
+            &ClusterItems::default(),
+        )
+        .unwrap();
+        let entities: Vec<_> = changes.iter().map(|c| c.entity.clone()).collect();
+        assert_eq!(
+            entities,
+            vec![
+                PromotionEntity::Ssl,
+                PromotionEntity::Upstream,
+                PromotionEntity::Route
+            ]
+        );
+        assert_eq!(changes[0].apply().unwrap().uri, "/ssls/c1");
+        assert_eq!(changes[0].unresolved, vec!["$secret://vault/1/c1/key"]);
+        assert_eq!(changes[2].unresolved, vec!["$env://AUTH_HEADER"]);
+
+        let missing = items(&[(
+            PromotionEntity::Upstream,
+            json!([{ "id": "u1", "tls": { "client_cert_id": "c1" } }]),
+        )]);
+        assert!(plan_promotion(
+            &PromotionEntity::Upstream,
+            "u1",
+            &missing,
+            &ClusterItems::default()
+        )
+        .is_err());
+    }
+}
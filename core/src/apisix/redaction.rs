@@ -0,0 +1,383 @@
+use super::{
+    base::PropertyType,
+    common::{EntityFields, EntityTrait},
+    consumer_groups::ConsumerGroupEntity,
+    consumers::ConsumerEntity,
+    plugins::registry::PluginRegistry,
+    routes::RouteEntity,
+    secrets::SecretEntity,
+    services::ServiceEntity,
+    stream_routes::StreamRouteEntity,
+    upstreams::UpstreamEntity,
+    validation::join_path,
+};
+use serde_json::Value;
+
+/// Replaces the sensitive values in the responses, the server keeps the stored value when an
+/// update sends it back
+pub const REDACTED: &str = "******";
+
+// Matches every key of a map or item of a list
+const ANY: &str = "*";
+
+// The panel doesn't edit the SSL objects, but their private keys go through the proxy
+const SSL_PATHS: &[&[&str]] = &[&["key"], &["keys", ANY]];
+
+// Keys of the APISIX config file, which is returned by the server
+const CONFIG_PATHS: &[&[&str]] = &[
+    &["apisix", "data_encryption", "keyring"],
+    &["deployment", "admin", "admin_key", ANY, "key"],
+    &["deployment", "etcd", "password"],
+];
+
+fn to_paths(paths: &[&[&str]]) -> Vec<Vec<String>> {
+    paths
+        .iter()
+        .map(|p| p.iter().map(|s| s.to_string()).collect())
+        .collect()
+}
+
+fn push_field_paths(
+    name: &str,
+    sensitive: bool,
+    property_type: &PropertyType,
+    prefix: &[String],
+    paths: &mut Vec<Vec<String>>,
+) {
+    let mut path = prefix.to_vec();
+    path.push(name.to_string());
+
+    if sensitive {
+        paths.push(path);
+        return;
+    }
+
+    match property_type {
+        PropertyType::Object(fields) => {
+            for field in fields {
+                push_field_paths(
+                    &field.name,
+                    field.sensitive,
+                    &field.property_type,
+                    &path,
+                    paths,
+                );
+            }
+        }
+        PropertyType::List(inner) | PropertyType::Map(inner) => {
+            if let PropertyType::Object(fields) = inner.as_ref() {
+                path.push(ANY.to_string());
+
+                for field in fields {
+                    push_field_paths(
+                        &field.name,
+                        field.sensitive,
+                        &field.property_type,
+                        &path,
+                        paths,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn entity_fields(entity_type: &str) -> Vec<EntityFields> {
+    match entity_type {
+        "consumer_groups" => ConsumerGroupEntity::value_fields(),
+        "consumers" => ConsumerEntity::value_fields(),
+        "routes" => RouteEntity::value_fields(),
+        "secrets" => SecretEntity::value_fields(),
+        "services" => ServiceEntity::value_fields(),
+        "stream_routes" => StreamRouteEntity::value_fields(),
+        "upstreams" => UpstreamEntity::value_fields(),
+        _ => vec![],
+    }
+}
+
+/// Paths of the sensitive values in the entities of an admin API uri, like `plugins.key-auth.key`
+pub fn sensitive_paths(uri: &str, registry: &PluginRegistry) -> Vec<Vec<String>> {
+    let entity_type = uri
+        .trim_start_matches('/')
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default();
+
+    if entity_type == "ssls" {
+        return to_paths(SSL_PATHS);
+    }
+
+    let mut paths = vec![];
+
+    for field in entity_fields(entity_type) {
+        push_field_paths(
+            &field.name,
+            field.sensitive,
+            &field.property_type,
+            &[],
+            &mut paths,
+        );
+    }
+
+    // The options are the same for every entity, so the plugin configs and global rules are
+    // also covered
+    for definition in registry.definitions() {
+        let prefix = ["plugins".to_string(), definition.name.clone()];
+
+        for option in &definition.options {
+            push_field_paths(
+                &option.name,
+                option.sensitive,
+                &option.property_type,
+                &prefix,
+                &mut paths,
+            );
+        }
+    }
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+pub fn config_sensitive_paths() -> Vec<Vec<String>> {
+    to_paths(CONFIG_PATHS)
+}
+
+// The references to secrets and env vars don't disclose the values
+fn is_reference(value: &Value) -> bool {
+    value
+        .as_str()
+        .is_some_and(|s| s.starts_with("$secret://") || s.starts_with("$env://"))
+}
+
+fn redact_path(value: &mut Value, path: &[String]) {
+    let Some((key, rest)) = path.split_first() else {
+        if !value.is_null() && !is_reference(value) {
+            *value = Value::String(REDACTED.to_string());
+        }
+        return;
+    };
+
+    match value {
+        Value::Object(map) if key == ANY => map.values_mut().for_each(|v| redact_path(v, rest)),
+        Value::Object(map) => {
+            if let Some(v) = map.get_mut(key) {
+                redact_path(v, rest);
+            }
+        }
+        Value::Array(items) if key == ANY => items.iter_mut().for_each(|v| redact_path(v, rest)),
+        _ => {}
+    }
+}
+
+/// Masks a value found at `at` in an entity, like the values of the promotion diffs
+pub fn redact_at(value: &mut Value, at: &[&str], paths: &[Vec<String>]) {
+    for path in paths {
+        let len = at.len().min(path.len());
+        let is_match = at[..len]
+            .iter()
+            .zip(&path[..len])
+            .all(|(a, p)| p == ANY || a == p);
+
+        if is_match {
+            redact_path(value, &path[len..]);
+        }
+    }
+}
+
+pub fn redact_entity(value: &mut Value, paths: &[Vec<String>]) {
+    redact_at(value, &[], paths);
+}
+
+/// Masks the entities of the list, get and write responses of the admin and Control APIs
+pub fn redact_response(response: &mut Value, paths: &[Vec<String>]) {
+    // The Control API returns the lists as arrays
+    if let Value::Array(list) = response {
+        for item in list {
+            redact_response(item, paths);
+        }
+    } else if let Some(Value::Array(list)) = response.get_mut("list") {
+        for item in list {
+            if let Some(value) = item.get_mut("value") {
+                redact_entity(value, paths);
+            }
+        }
+    } else if let Some(value) = response.get_mut("value") {
+        redact_entity(value, paths);
+    }
+}
+
+fn contains_redacted(value: &Value, path: &[String]) -> bool {
+    let Some((key, rest)) = path.split_first() else {
+        return value.as_str() == Some(REDACTED);
+    };
+
+    match value {
+        Value::Object(map) if key == ANY => map.values().any(|v| contains_redacted(v, rest)),
+        Value::Object(map) => map.get(key).is_some_and(|v| contains_redacted(v, rest)),
+        Value::Array(items) if key == ANY => items.iter().any(|v| contains_redacted(v, rest)),
+        _ => false,
+    }
+}
+
+pub fn has_redacted(value: &Value, paths: &[Vec<String>]) -> bool {
+    paths.iter().any(|path| contains_redacted(value, path))
+}
+
+fn restore_path(
+    value: &mut Value,
+    stored: Option<&Value>,
+    path: &[String],
+    location: &str,
+) -> Result<(), String> {
+    let Some((key, rest)) = path.split_first() else {
+        if value.as_str() == Some(REDACTED) {
+            *value = stored
+                .cloned()
+                .ok_or_else(|| format!("{location} has the masked value, it must be set again"))?;
+        }
+        return Ok(());
+    };
+
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut().filter(|(k, _)| key == ANY || *k == key) {
+                let location = join_path(location, k);
+                restore_path(v, stored.and_then(|s| s.get(k)), rest, &location)?;
+            }
+        }
+        Value::Array(items) if key == ANY => {
+            // The items are matched by position, which is wrong after adding, removing or
+            // reordering them
+            let stored_len = stored.and_then(Value::as_array).map(Vec::len);
+            let is_masked = items.iter().any(|v| contains_redacted(v, rest));
+
+            if is_masked && stored_len.is_some_and(|len| len != items.len()) {
+                return Err(format!(
+                    "{location} has masked values and a different number of items than the stored ones, they must be set again"
+                ));
+            }
+
+            for (idx, v) in items.iter_mut().enumerate() {
+                let location = join_path(location, &idx.to_string());
+                restore_path(v, stored.and_then(|s| s.get(idx)), rest, &location)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Replaces the masks sent back in an update with the stored values
+pub fn restore_redacted(
+    value: &mut Value,
+    stored: Option<&Value>,
+    paths: &[Vec<String>],
+) -> Result<(), String> {
+    for path in paths {
+        restore_path(value, stored, path, "")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apisix::plugins::wasm_plugins::wasm_plugin_definitions;
+    use serde_json::json;
+
+    fn consumer_paths() -> Vec<Vec<String>> {
+        sensitive_paths("/consumers", &PluginRegistry::new())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let paths = consumer_paths();
+        let stored = json!({
+            "username": "foo",
+            "plugins": { "key-auth": { "key": "secret-key" } },
+        });
+
+        let mut value = stored.clone();
+        redact_entity(&mut value, &paths);
+        assert_eq!(value["plugins"]["key-auth"]["key"], REDACTED);
+        assert!(has_redacted(&value, &paths));
+
+        value["desc"] = json!("updated");
+        restore_redacted(&mut value, Some(&stored), &paths).unwrap();
+        assert_eq!(value["plugins"]["key-auth"]["key"], "secret-key");
+        assert_eq!(value["desc"], "updated");
+        assert!(!has_redacted(&value, &paths));
+    }
+
+    #[test]
+    fn test_create_with_mask() {
+        let paths = consumer_paths();
+        let mut value = json!({
+            "username": "foo",
+            "plugins": { "key-auth": { "key": REDACTED } },
+        });
+
+        assert_eq!(
+            restore_redacted(&mut value, None, &paths),
+            Err("plugins.key-auth.key has the masked value, it must be set again".to_string())
+        );
+    }
+
+    #[test]
+    fn test_references() {
+        let paths = consumer_paths();
+        let mut value = json!({
+            "username": "foo",
+            "plugins": { "key-auth": { "key": "$secret://vault/1/foo/key" } },
+        });
+
+        redact_entity(&mut value, &paths);
+        assert_eq!(
+            value["plugins"]["key-auth"]["key"],
+            "$secret://vault/1/foo/key"
+        );
+        assert!(!has_redacted(&value, &paths));
+    }
+
+    #[test]
+    fn test_list_items() {
+        let paths = sensitive_paths("/ssls/1", &PluginRegistry::new());
+        let stored = json!({ "certs": ["a", "b"], "keys": ["key-a", "key-b"] });
+
+        let mut value = stored.clone();
+        redact_entity(&mut value, &paths);
+        assert_eq!(value["keys"], json!([REDACTED, REDACTED]));
+
+        let mut same = value.clone();
+        restore_redacted(&mut same, Some(&stored), &paths).unwrap();
+        assert_eq!(same["keys"], stored["keys"]);
+
+        let mut removed = json!({ "certs": ["b"], "keys": [REDACTED] });
+        assert!(restore_redacted(&mut removed, Some(&stored), &paths)
+            .unwrap_err()
+            .starts_with("keys has masked values"));
+
+        // Replacing all the keys doesn't need the stored ones
+        let mut replaced = json!({ "certs": ["c"], "keys": ["key-c"] });
+        assert!(restore_redacted(&mut replaced, Some(&stored), &paths).is_ok());
+    }
+
+    #[test]
+    fn test_wasm_plugins() {
+        let mut registry = PluginRegistry::new();
+        registry.extend(wasm_plugin_definitions(&json!({
+            "wasm": { "plugins": [{ "name": "wasm-auth", "file": "auth.wasm", "priority": 1 }] },
+        })));
+
+        let paths = sensitive_paths("/routes/1", &registry);
+        let mut value = json!({ "plugins": { "wasm-auth": { "conf": { "token": "foo" } } } });
+
+        redact_entity(&mut value, &paths);
+        assert_eq!(value["plugins"]["wasm-auth"]["conf"], REDACTED);
+    }
+}
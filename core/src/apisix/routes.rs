@@ -6,6 +6,7 @@ use crate::{
     apisix::{
         base::{PropertyType, Required},
         common::EntityFields,
+        upstreams::UpstreamConfig,
     },
     macros::derive_common_default,
     proxy::ProxyFetchOpts,
@@ -23,6 +24,7 @@ impl EntityItemTrait for Route {
     const DISPLAY_SHORT: &'static [&'static str] = &["uri"];
     const DOCS_KEY: &'static str = "route";
     const PLUGIN_ENTITY: Option<PluginEntities> = Some(PluginEntities::Route);
+    const SCHEMA_KEY: Option<&'static str> = Some("route");
 
     entity_trait_get_value!();
 }
@@ -52,6 +54,8 @@ impl EntityTrait for RouteEntity {
     fn create(&self) -> Result<ProxyFetchOpts, String> {
         let (id, mut new_route_values) = self.get_parsed_values();
 
+        UpstreamConfig::validate_inline(&new_route_values)?;
+
         let existing_plugins = new_route_values
             .get("plugins")
             .unwrap_or(&json!({}))
@@ -71,6 +75,8 @@ impl EntityTrait for RouteEntity {
     fn update(&self) -> Result<ProxyFetchOpts, String> {
         let (id, new_route_values) = self.get_parsed_values();
 
+        UpstreamConfig::validate_inline(&new_route_values)?;
+
         let data = serde_json::to_string(&new_route_values).ok();
         let uri = format!("{}/{}", Route::API_PREFIX, id);
 
@@ -131,7 +137,7 @@ impl EntityTrait for RouteEntity {
                 description: "Attributes of the Route specified as key-value pairs.".to_string(),
                 example: Some(r#"{"version":"v2","build":"16","env":"production"}"#.to_string()),
                 name: "labels".to_string(),
-                property_type: PropertyType::JSON,
+                property_type: PropertyType::Map(Box::new(PropertyType::String)),
                 ..Default::default()
             },
             EntityFields {
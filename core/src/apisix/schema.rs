@@ -0,0 +1,847 @@
+use super::{
+    base::{PropertyField, PropertyType, Required},
+    plugins::common::{PluginDefinition, PluginEntities, PluginOption},
+    validation::{plugin_config_issues, FieldError},
+};
+use crate::macros::derive_common_default;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+derive_common_default! {
+pub struct PluginSchema {
+    pub schema: Option<Value>,
+    pub consumer_schema: Option<Value>,
+    pub metadata_schema: Option<Value>,
+    pub priority: Option<i64>,
+    pub version: Option<f64>,
+    #[serde(rename = "type")]
+    pub plugin_type: Option<String>,
+}}
+
+// Response of the `/v1/schema` endpoint of the Control API
+derive_common_default! {
+pub struct ApisixSchema {
+    pub main: HashMap<String, Value>,
+    pub plugins: HashMap<String, PluginSchema>,
+    pub stream_plugins: Option<HashMap<String, PluginSchema>>,
+}}
+
+impl ApisixSchema {
+    pub fn plugin_schema(&self, name: &str, entity: &PluginEntities) -> Option<&Value> {
+        let plugin = self.plugins.get(name)?;
+
+        match entity {
+            PluginEntities::Consumer => plugin.consumer_schema.as_ref().or(plugin.schema.as_ref()),
+            _ => plugin.schema.as_ref(),
+        }
+    }
+
+    /// Generates the definitions of every enabled plugin. The hand-written definitions are only
+    /// used for the descriptions, the code types and the sensitive values of the options, which
+    /// the schema doesn't have.
+    pub fn plugin_definitions(&self, hand_written: &[PluginDefinition]) -> Vec<PluginDefinition> {
+        let mut names = self.plugins.keys().collect::<Vec<&String>>();
+        names.sort();
+
+        let mut definitions = vec![];
+
+        for name in names {
+            let plugin = &self.plugins[name];
+            let mut entities = vec![
+                PluginEntities::Route,
+                PluginEntities::Service,
+                PluginEntities::ConsumerGroup,
+            ];
+
+            // Auth plugins have a different config in the consumer than in the route
+            match &plugin.consumer_schema {
+                Some(consumer_schema) => definitions.push(generate_definition(
+                    name,
+                    consumer_schema,
+                    vec![PluginEntities::Consumer],
+                    hand_written,
+                )),
+                None => entities.push(PluginEntities::Consumer),
+            }
+
+            let schema = plugin.schema.clone().unwrap_or_default();
+            definitions.push(generate_definition(name, &schema, entities, hand_written));
+        }
+
+        definitions
+    }
+
+    /// Validates the body of an entity and the config of each of its plugins
+    pub fn validate_entity(
+        &self,
+        schema_key: &str,
+        plugin_entity: Option<&PluginEntities>,
+        body: &Value,
+    ) -> Result<(), Vec<FieldError>> {
+        let mut errors = vec![];
+
+        if let Some(schema) = self.main.get(schema_key) {
+            validate_schema(schema, body, "", &mut errors);
+        }
+
+        let plugins = body.get("plugins").and_then(|p| p.as_object());
+
+        if let (Some(entity), Some(plugins)) = (plugin_entity, plugins) {
+            for (name, config) in plugins.iter() {
+                let pointer = format!("/plugins/{}", escape_pointer(name));
+
+                match self.plugin_schema(name, entity) {
+                    Some(schema) => {
+                        validate_schema(schema, config, &pointer, &mut errors);
+
+                        for issue in plugin_config_issues(name, config) {
+                            errors.push(issue.to_pointer_error(&pointer));
+                        }
+                    }
+                    None => errors.push(FieldError::new(
+                        &pointer,
+                        "plugin is not enabled in the running APISIX",
+                    )),
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+// The schema doesn't describe the code nor the sensitive values, they are kept from the
+// hand-written fields with the same name and type
+fn merge_property_type(generated: PropertyType, hand_written: &PropertyType) -> PropertyType {
+    match (generated, hand_written) {
+        (PropertyType::String, PropertyType::Code(language)) => {
+            PropertyType::Code(language.clone())
+        }
+        (PropertyType::List(item), PropertyType::List(existing)) => {
+            PropertyType::List(Box::new(merge_property_type(*item, existing)))
+        }
+        (PropertyType::Map(value), PropertyType::Map(existing)) => {
+            PropertyType::Map(Box::new(merge_property_type(*value, existing)))
+        }
+        (PropertyType::Object(fields), PropertyType::Object(existing)) => PropertyType::Object(
+            fields
+                .into_iter()
+                .map(
+                    |field| match existing.iter().find(|e| e.name == field.name) {
+                        Some(existing_field) => merge_field(field, existing_field),
+                        None => field,
+                    },
+                )
+                .collect(),
+        ),
+        (generated, _) => generated,
+    }
+}
+
+fn merge_field(field: PropertyField, existing: &PropertyField) -> PropertyField {
+    PropertyField {
+        description: Some(existing.description.clone())
+            .filter(|d| !d.is_empty())
+            .unwrap_or(field.description),
+        property_type: merge_property_type(field.property_type, &existing.property_type),
+        sensitive: existing.sensitive,
+        ..field
+    }
+}
+
+fn generate_definition(
+    name: &str,
+    schema: &Value,
+    entities: Vec<PluginEntities>,
+    hand_written: &[PluginDefinition],
+) -> PluginDefinition {
+    let entities: HashSet<PluginEntities> = entities.into_iter().collect();
+    let existing = hand_written
+        .iter()
+        .find(|d| d.name == name && !d.entities.is_disjoint(&entities));
+
+    let options = schema_fields(schema)
+        .into_iter()
+        .map(|field| {
+            let existing_option =
+                existing.and_then(|d| d.options.iter().find(|o| o.name == field.name));
+
+            PluginOption {
+                default_value: field.default_value,
+                description: existing_option
+                    .map(|o| o.description.clone())
+                    .filter(|d| !d.is_empty())
+                    .unwrap_or(field.description),
+                is_required: field.is_required,
+                is_secret: existing_option.is_some_and(|o| o.is_secret),
+                name: field.name,
+                property_type: match existing_option {
+                    Some(o) => merge_property_type(field.property_type, &o.property_type),
+                    None => field.property_type,
+                },
+                sensitive: existing_option.is_some_and(|o| o.sensitive),
+                // Everything in the schema is supported by the running APISIX
+                version_range: None,
+            }
+        })
+        .collect();
+
+    PluginDefinition {
+        entities,
+        name: name.to_string(),
+        options,
+        version_range: None,
+    }
+}
+
+fn schema_types(schema: &Map<String, Value>) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(|t| t.as_str()).collect(),
+        _ => vec![],
+    }
+}
+
+fn property_type_from_schema(schema: &Value) -> PropertyType {
+    let Some(schema) = schema.as_object() else {
+        return PropertyType::JSON;
+    };
+
+    if let Some(options) = schema.get("enum").and_then(|e| e.as_array()) {
+        let options = options
+            .iter()
+            .filter_map(|o| o.as_str())
+            .map(String::from)
+            .collect::<Vec<String>>();
+
+        if !options.is_empty() {
+            return PropertyType::Enum(options);
+        }
+    }
+
+    let types = schema_types(schema);
+    let main_type = types.iter().find(|t| **t != "null").copied();
+
+    match main_type {
+        Some("boolean") => PropertyType::Boolean,
+        Some("integer") | Some("number") => PropertyType::Number,
+        Some("string") => PropertyType::String,
+        Some("array") => {
+            let item_type = schema
+                .get("items")
+                .map(property_type_from_schema)
+                .unwrap_or_default();
+
+            PropertyType::List(Box::new(item_type))
+        }
+        Some("object") => {
+            let has_properties = schema
+                .get("properties")
+                .and_then(|p| p.as_object())
+                .is_some_and(|p| !p.is_empty());
+
+            if has_properties {
+                return PropertyType::Object(schema_fields(&Value::Object(schema.clone())));
+            }
+
+            match schema.get("additionalProperties") {
+                Some(additional) if additional.is_object() => {
+                    PropertyType::Map(Box::new(property_type_from_schema(additional)))
+                }
+                _ => PropertyType::JSON,
+            }
+        }
+        _ => PropertyType::JSON,
+    }
+}
+
+fn default_value_from_schema(schema: &Value) -> Option<String> {
+    match schema.get("default")? {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+// Handles the common `anyOf: [{required: [a]}, {required: [b]}]` where only one key is needed
+fn alternative_required(schema: &Map<String, Value>) -> HashMap<String, Vec<String>> {
+    let mut result = HashMap::new();
+    let alternatives = schema
+        .get("anyOf")
+        .or(schema.get("oneOf"))
+        .and_then(|a| a.as_array());
+
+    let Some(alternatives) = alternatives else {
+        return result;
+    };
+
+    let keys = alternatives
+        .iter()
+        .map(|alternative| {
+            let required = alternative.get("required")?.as_array()?;
+
+            match required.as_slice() {
+                [Value::String(key)] => Some(key.clone()),
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<String>>>();
+
+    for key in keys.unwrap_or_default().iter() {
+        let others = alternatives
+            .iter()
+            .filter_map(|a| a.get("required")?.get(0)?.as_str())
+            .filter(|other| other != key)
+            .map(String::from)
+            .collect();
+
+        result.insert(key.clone(), others);
+    }
+
+    result
+}
+
+fn schema_fields(schema: &Value) -> Vec<PropertyField> {
+    let Some(schema) = schema.as_object() else {
+        return vec![];
+    };
+    let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) else {
+        return vec![];
+    };
+
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|k| k.as_str()).collect())
+        .unwrap_or_default();
+    let mut alternatives = alternative_required(schema);
+
+    properties
+        .iter()
+        // Keys like `_meta` are added by APISIX to every plugin
+        .filter(|(name, _)| !name.starts_with('_'))
+        .map(|(name, property)| {
+            let is_required = if required.contains(&name.as_str()) {
+                Required::True
+            } else if let Some(others) = alternatives.remove(name) {
+                Required::TrueIfOtherMissing(others)
+            } else {
+                Required::False
+            };
+
+            PropertyField {
+                default_value: default_value_from_schema(property),
+                description: property
+                    .get("description")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                is_required,
+                name: name.clone(),
+                property_type: property_type_from_schema(property),
+                sensitive: false,
+            }
+        })
+        .collect()
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn type_matches(type_name: &str, value: &Value) -> bool {
+    match type_name {
+        "array" => value.is_array(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "object" => value.is_object(),
+        "string" => value.is_string(),
+        _ => true,
+    }
+}
+
+fn is_valid(schema: &Value, value: &Value) -> bool {
+    let mut errors = vec![];
+    validate_schema(schema, value, "", &mut errors);
+    errors.is_empty()
+}
+
+// Keywords that aren't validated, a value that passes a schema with them may still not match it
+const UNCHECKED_KEYWORDS: &[&str] = &["format", "pattern", "patternProperties"];
+
+fn has_unchecked_keywords(schema: &Value) -> bool {
+    match schema {
+        Value::Object(map) => map.iter().any(|(key, v)| match key.as_str() {
+            // The keys are the names of the properties
+            "properties" => v
+                .as_object()
+                .is_some_and(|p| p.values().any(has_unchecked_keywords)),
+            key => UNCHECKED_KEYWORDS.contains(&key) || has_unchecked_keywords(v),
+        }),
+        Value::Array(items) => items.iter().any(has_unchecked_keywords),
+        _ => false,
+    }
+}
+
+// None when it is unknown, e.g. the branches of a `oneOf` that only differ by the pattern
+fn matches_schema(schema: &Value, value: &Value) -> Option<bool> {
+    if !is_valid(schema, value) {
+        Some(false)
+    } else if has_unchecked_keywords(schema) {
+        None
+    } else {
+        Some(true)
+    }
+}
+
+fn validate_number(
+    schema: &Map<String, Value>,
+    num: f64,
+    pointer: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    let get = |key: &str| schema.get(key).and_then(|v| v.as_f64());
+    // Draft 4 uses booleans for the exclusive limits, later drafts use numbers
+    let is_exclusive = |key: &str| schema.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if let Some(min) = get("minimum") {
+        if num == min && is_exclusive("exclusiveMinimum") {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be greater than {}", min),
+            ));
+        } else if num < min {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be at least {}", min),
+            ));
+        }
+    }
+    if let Some(max) = get("maximum") {
+        if num == max && is_exclusive("exclusiveMaximum") {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be less than {}", max),
+            ));
+        } else if num > max {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be at most {}", max),
+            ));
+        }
+    }
+    if let Some(min) = get("exclusiveMinimum") {
+        if num <= min {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be greater than {}", min),
+            ));
+        }
+    }
+    if let Some(max) = get("exclusiveMaximum") {
+        if num >= max {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be less than {}", max),
+            ));
+        }
+    }
+}
+
+fn validate_object(
+    schema: &Map<String, Value>,
+    obj: &Map<String, Value>,
+    pointer: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    let properties = schema.get("properties").and_then(|p| p.as_object());
+
+    if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+        for key in required.iter().filter_map(|k| k.as_str()) {
+            if !obj.contains_key(key) {
+                let child = format!("{}/{}", pointer, escape_pointer(key));
+                errors.push(FieldError::new(&child, "is required"));
+            }
+        }
+    }
+
+    if let Some(min) = schema.get("minProperties").and_then(|m| m.as_u64()) {
+        if (obj.len() as u64) < min {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must have at least {} properties", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maxProperties").and_then(|m| m.as_u64()) {
+        if (obj.len() as u64) > max {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must have at most {} properties", max),
+            ));
+        }
+    }
+
+    // Patterns are not evaluated, so keys are only checked without them
+    let has_patterns = schema.contains_key("patternProperties");
+
+    for (key, child_value) in obj.iter() {
+        let child = format!("{}/{}", pointer, escape_pointer(key));
+
+        if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+            validate_schema(child_schema, child_value, &child, errors);
+            continue;
+        }
+
+        if has_patterns {
+            continue;
+        }
+
+        match schema.get("additionalProperties") {
+            Some(Value::Bool(false)) => {
+                errors.push(FieldError::new(&child, "is not an allowed property"));
+            }
+            Some(additional) if additional.is_object() => {
+                validate_schema(additional, child_value, &child, errors);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(dependencies) = schema.get("dependencies").and_then(|d| d.as_object()) {
+        for (key, dependency) in dependencies.iter() {
+            if !obj.contains_key(key) {
+                continue;
+            }
+
+            match dependency {
+                Value::Array(keys) => {
+                    for dep in keys.iter().filter_map(|k| k.as_str()) {
+                        if !obj.contains_key(dep) {
+                            let child = format!("{}/{}", pointer, escape_pointer(dep));
+                            let message = format!("is required when {} is present", key);
+                            errors.push(FieldError::new(&child, &message));
+                        }
+                    }
+                }
+                Value::Object(_) => {
+                    validate_schema(dependency, &Value::Object(obj.clone()), pointer, errors);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn validate_array(
+    schema: &Map<String, Value>,
+    items: &[Value],
+    pointer: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64()) {
+        if (items.len() as u64) < min {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must have at least {} items", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maxItems").and_then(|m| m.as_u64()) {
+        if (items.len() as u64) > max {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must have at most {} items", max),
+            ));
+        }
+    }
+    if schema.get("uniqueItems").and_then(|u| u.as_bool()) == Some(true) {
+        let has_duplicates = items
+            .iter()
+            .enumerate()
+            .any(|(idx, item)| items[..idx].contains(item));
+
+        if has_duplicates {
+            errors.push(FieldError::new(pointer, "must have unique items"));
+        }
+    }
+
+    match schema.get("items") {
+        Some(Value::Array(tuple)) => {
+            for (idx, (item_schema, item)) in tuple.iter().zip(items.iter()).enumerate() {
+                validate_schema(item_schema, item, &format!("{}/{}", pointer, idx), errors);
+            }
+        }
+        Some(item_schema) if item_schema.is_object() => {
+            for (idx, item) in items.iter().enumerate() {
+                validate_schema(item_schema, item, &format!("{}/{}", pointer, idx), errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Validates a value against the subset of JSON Schema used by APISIX. The errors use JSON
+/// pointers (e.g. `/plugins/limit-count/count`). The `pattern` and `format` keywords are skipped,
+/// so the `oneOf`, `not` and `if` schemas that use them don't report errors when they match.
+pub fn validate_schema(schema: &Value, value: &Value, pointer: &str, errors: &mut Vec<FieldError>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(type_value) = schema.get("type") {
+        let types: Vec<&str> = match type_value {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(|t| t.as_str()).collect(),
+            _ => vec![],
+        };
+
+        if !types.is_empty() && !types.iter().any(|t| type_matches(t, value)) {
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be of type {}", types.join(" or ")),
+            ));
+            return;
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !options.contains(value) {
+            let options = options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>();
+            errors.push(FieldError::new(
+                pointer,
+                &format!("must be one of: {}", options.join(", ")),
+            ));
+        }
+    }
+
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(FieldError::new(pointer, &format!("must be {}", expected)));
+        }
+    }
+
+    match value {
+        Value::Number(num) => {
+            validate_number(schema, num.as_f64().unwrap_or_default(), pointer, errors)
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+
+            if let Some(min) = schema.get("minLength").and_then(|m| m.as_u64()) {
+                if len < min {
+                    errors.push(FieldError::new(
+                        pointer,
+                        &format!("must have at least {} characters", min),
+                    ));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(|m| m.as_u64()) {
+                if len > max {
+                    errors.push(FieldError::new(
+                        pointer,
+                        &format!("must have at most {} characters", max),
+                    ));
+                }
+            }
+        }
+        Value::Array(items) => validate_array(schema, items, pointer, errors),
+        Value::Object(obj) => validate_object(schema, obj, pointer, errors),
+        _ => {}
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for sub_schema in all_of {
+            validate_schema(sub_schema, value, pointer, errors);
+        }
+    }
+
+    if let Some(any_of) = schema.get("anyOf").and_then(|a| a.as_array()) {
+        if !any_of.iter().any(|s| is_valid(s, value)) {
+            errors.push(FieldError::new(
+                pointer,
+                "must match at least one of the allowed schemas",
+            ));
+        }
+    }
+
+    if let Some(one_of) = schema.get("oneOf").and_then(|a| a.as_array()) {
+        let results: Vec<Option<bool>> = one_of.iter().map(|s| matches_schema(s, value)).collect();
+        let matches = results.iter().filter(|r| **r == Some(true)).count();
+        let unknown = results.iter().filter(|r| r.is_none()).count();
+
+        if matches > 1 || matches + unknown == 0 {
+            errors.push(FieldError::new(
+                pointer,
+                "must match exactly one of the allowed schemas",
+            ));
+        }
+    }
+
+    if let Some(not) = schema.get("not") {
+        if matches_schema(not, value) == Some(true) {
+            errors.push(FieldError::new(
+                pointer,
+                "must not match the disallowed schema",
+            ));
+        }
+    }
+
+    if let Some(if_schema) = schema.get("if") {
+        let branch = match matches_schema(if_schema, value) {
+            Some(true) => schema.get("then"),
+            Some(false) => schema.get("else"),
+            None => None,
+        };
+
+        if let Some(branch_schema) = branch {
+            validate_schema(branch_schema, value, pointer, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apisix::plugins::{registry::PluginRegistry, wasm_plugins::wasm_plugin_definitions};
+    use serde_json::json;
+
+    fn errors(schema: &Value, value: &Value) -> Vec<FieldError> {
+        let mut errors = vec![];
+        validate_schema(schema, value, "", &mut errors);
+        errors
+    }
+
+    #[test]
+    fn test_hand_written_annotations() {
+        let schema: ApisixSchema = serde_json::from_value(json!({
+            "main": {},
+            "plugins": {
+                "elasticsearch-logger": { "schema": { "properties": {
+                    "auth": { "type": "object", "properties": {
+                        "username": { "type": "string" },
+                        "password": { "type": "string" },
+                    } },
+                } } },
+                "serverless-pre-function": { "schema": { "properties": {
+                    "functions": { "type": "array", "items": { "type": "string" } },
+                } } },
+                "wasm-auth": { "schema": { "properties": {
+                    "conf": { "oneOf": [{ "type": "object" }, { "type": "string" }] },
+                } } },
+            },
+        }))
+        .unwrap();
+        let mut registry = PluginRegistry::new();
+        registry.extend(wasm_plugin_definitions(&json!({
+            "wasm": { "plugins": [{ "name": "wasm-auth", "file": "auth.wasm", "priority": 1 }] },
+        })));
+
+        let definitions = schema.plugin_definitions(registry.definitions());
+        let option = |plugin: &str, name: &str| {
+            definitions
+                .iter()
+                .find(|d| d.name == plugin)
+                .and_then(|d| d.options.iter().find(|o| o.name == name))
+                .cloned()
+                .unwrap()
+        };
+
+        assert_eq!(
+            option("serverless-pre-function", "functions").property_type,
+            PropertyType::List(Box::new(PropertyType::Code("lua".to_string())))
+        );
+        assert!(option("wasm-auth", "conf").sensitive);
+
+        let PropertyType::Object(fields) = option("elasticsearch-logger", "auth").property_type
+        else {
+            panic!("auth must be an object");
+        };
+        let sensitive: Vec<(String, bool)> =
+            fields.into_iter().map(|f| (f.name, f.sensitive)).collect();
+        assert!(sensitive.contains(&("password".to_string(), true)));
+        assert!(sensitive.contains(&("username".to_string(), false)));
+    }
+
+    #[test]
+    fn test_one_of() {
+        let schema = json!({
+            "oneOf": [
+                { "type": "string", "minLength": 3 },
+                { "type": "integer" },
+                { "type": "number" },
+            ],
+        });
+
+        assert!(errors(&schema, &json!("abc")).is_empty());
+        assert_eq!(errors(&schema, &json!("a")).len(), 1);
+        // Both the integer and the number branches match
+        assert_eq!(errors(&schema, &json!(1)).len(), 1);
+        assert!(errors(&schema, &json!(1.5)).is_empty());
+    }
+
+    #[test]
+    fn test_one_of_unchecked_keywords() {
+        let schema = json!({
+            "oneOf": [
+                { "type": "string", "format": "ipv4" },
+                { "type": "string", "format": "ipv6" },
+            ],
+        });
+
+        assert!(errors(&schema, &json!("127.0.0.1")).is_empty());
+        assert!(errors(&schema, &json!("::1")).is_empty());
+        assert_eq!(errors(&schema, &json!(1)).len(), 1);
+
+        let schema = json!({
+            "oneOf": [
+                { "type": "object", "properties": { "host": { "type": "string", "pattern": "^a" } } },
+                { "type": "object", "properties": { "host": { "type": "string", "pattern": "^b" } } },
+            ],
+        });
+        assert!(errors(&schema, &json!({ "host": "a" })).is_empty());
+
+        // The property names aren't keywords
+        let schema = json!({
+            "oneOf": [
+                { "type": "object", "properties": { "pattern": { "type": "string" } } },
+                { "type": "object" },
+            ],
+        });
+        assert_eq!(errors(&schema, &json!({ "pattern": "a" })).len(), 1);
+    }
+
+    #[test]
+    fn test_not_and_if() {
+        let schema = json!({ "not": { "type": "string", "pattern": "^a" } });
+        assert!(errors(&schema, &json!("b")).is_empty());
+        assert_eq!(
+            errors(&json!({ "not": { "type": "string" } }), &json!("b")).len(),
+            1
+        );
+
+        let schema = json!({
+            "if": { "type": "string", "format": "ipv4" },
+            "then": { "maxLength": 3 },
+            "else": { "minLength": 10 },
+        });
+        assert!(errors(&schema, &json!("::1")).is_empty());
+
+        let schema = json!({
+            "if": { "type": "string" },
+            "then": { "maxLength": 3 },
+            "else": { "minimum": 10 },
+        });
+        assert_eq!(errors(&schema, &json!("abcd")).len(), 1);
+        assert_eq!(errors(&schema, &json!(5)).len(), 1);
+    }
+}
@@ -27,7 +27,8 @@ pub type SecretEntity = Entity<Secret>;
 impl EntityTrait for SecretEntity {
     fn create(&self) -> Result<ProxyFetchOpts, String> {
         let (_, data) = self.get_common_parsed_values();
-        let uri = format!("{}/vault", Secret::API_PREFIX).to_string();
+        // The admin API requires the id in the uri, the prefix already includes the manager
+        let uri = format!("{}/{}", Secret::API_PREFIX, self.check_id()?);
 
         Ok(ProxyFetchOpts {
             uri,
@@ -38,7 +39,7 @@ impl EntityTrait for SecretEntity {
 
     fn update(&self) -> Result<ProxyFetchOpts, String> {
         let (id, data) = self.get_common_parsed_values();
-        let uri = format!("{}/vault/{}", Secret::API_PREFIX, id).to_string();
+        let uri = format!("{}/{}", Secret::API_PREFIX, id);
 
         Ok(ProxyFetchOpts {
             uri,
@@ -67,6 +68,7 @@ impl EntityTrait for SecretEntity {
                 description: "vault token".to_string(),
                 is_required: Required::True,
                 name: "token".to_string(),
+                sensitive: true,
                 ..EntityFields::default()
             },
             EntityFields {
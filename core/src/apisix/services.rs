@@ -2,7 +2,11 @@ use super::{
     common::{prelude::*, Entity, EntityFields, EntityValue, GetListResponse, Unit},
     plugins::common::PluginEntities,
 };
-use crate::{apisix::base::PropertyType, macros::derive_common_default, proxy::ProxyFetchOpts};
+use crate::{
+    apisix::{base::PropertyType, upstreams::UpstreamConfig},
+    macros::derive_common_default,
+    proxy::ProxyFetchOpts,
+};
 
 derive_common_default! {
 pub struct ServiceValue(pub EntityValue);}
@@ -15,6 +19,7 @@ impl EntityItemTrait for Service {
     const DISPLAY_SHORT: &'static [&'static str] = &[];
     const DOCS_KEY: &'static str = "service";
     const PLUGIN_ENTITY: Option<PluginEntities> = Some(PluginEntities::Service);
+    const SCHEMA_KEY: Option<&'static str> = Some("service");
 
     entity_trait_get_value!();
 }
@@ -25,6 +30,8 @@ pub type GetServicesResponse = GetListResponse<Service>;
 
 impl EntityTrait for ServiceEntity {
     fn create(&self) -> Result<ProxyFetchOpts, String> {
+        UpstreamConfig::validate_inline(&self.parsed.get_cloned())?;
+
         let (id, data) = self.get_common_parsed_values();
         let (uri, method) = EntityValue::common_create(Service::API_PREFIX, id);
 
@@ -32,6 +39,8 @@ impl EntityTrait for ServiceEntity {
     }
 
     fn update(&self) -> Result<ProxyFetchOpts, String> {
+        UpstreamConfig::validate_inline(&self.parsed.get_cloned())?;
+
         let (id, data) = self.get_common_parsed_values();
         let (uri, method) = EntityValue::common_update(Service::API_PREFIX, id);
 
@@ -76,7 +85,7 @@ impl EntityTrait for ServiceEntity {
             EntityFields {
                 description: "Attributes of the Service specified as key-value pairs.".to_string(),
                 name: "labels".to_string(),
-                property_type: PropertyType::JSON,
+                property_type: PropertyType::Map(Box::new(PropertyType::String)),
                 ..EntityFields::default()
             },
             EntityFields {
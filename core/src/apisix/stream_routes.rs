@@ -13,6 +13,7 @@ impl EntityItemTrait for StreamRoute {
     const API_PREFIX: &'static str = "/stream_routes";
     const DOCS_KEY: &'static str = "stream-route";
     const PLUGIN_ENTITY: Option<PluginEntities> = None;
+    const SCHEMA_KEY: Option<&'static str> = Some("stream_route");
     const REQUIRED_VERSION: Option<&'static str> = Some("3.2.1");
 
     entity_trait_get_value!();
@@ -3,10 +3,16 @@ use super::{
     plugins::common::PluginEntities,
 };
 use crate::{
-    apisix::{base::PropertyType, common::EntityFields},
-    macros::derive_common_default,
+    apisix::{
+        base::{PropertyField, PropertyType, Required},
+        common::EntityFields,
+    },
+    macros::{derive_common, derive_common_default},
     proxy::ProxyFetchOpts,
 };
+use serde_json::Value;
+use std::collections::HashMap;
+use ts_rs::TS;
 
 derive_common_default! {
 pub struct UpstreamValue(pub EntityValue);}
@@ -17,6 +23,7 @@ impl EntityItemTrait for Upstream {
     const API_PREFIX: &'static str = "/upstreams";
     const DOCS_KEY: &'static str = "upstream";
     const PLUGIN_ENTITY: Option<PluginEntities> = None;
+    const SCHEMA_KEY: Option<&'static str> = Some("upstream");
 
     entity_trait_get_value!();
 }
@@ -25,8 +32,192 @@ pub type UpstreamEntity = Entity<Upstream>;
 
 pub type GetUpstreamsResponse = GetListResponse<Upstream>;
 
+fn node_fields() -> Vec<PropertyField> {
+    vec![
+        PropertyField {
+            description: "IP address or domain of the node. IPv6 addresses with a port are wrapped in brackets.".to_string(),
+            is_required: Required::True,
+            name: "host".to_string(),
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Port of the node. Defaults to the port of the scheme.".to_string(),
+            name: "port".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Weight of the node in the load balancing.".to_string(),
+            is_required: Required::True,
+            name: "weight".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+        PropertyField {
+            default_value: Some("0".to_string()),
+            description: "Priority of the node. The nodes with a lower priority are only used when the higher ones are unavailable.".to_string(),
+            name: "priority".to_string(),
+            property_type: PropertyType::Number,
+            ..Default::default()
+        },
+    ]
+}
+
+fn check_type_field() -> PropertyField {
+    PropertyField {
+        default_value: Some("http".to_string()),
+        description: "Type of the health check.".to_string(),
+        name: "type".to_string(),
+        property_type: PropertyType::Enum(vec![
+            "http".to_string(),
+            "https".to_string(),
+            "tcp".to_string(),
+        ]),
+        ..Default::default()
+    }
+}
+
+fn http_statuses_field(description: &str) -> PropertyField {
+    PropertyField {
+        description: description.to_string(),
+        name: "http_statuses".to_string(),
+        property_type: PropertyType::List(Box::new(PropertyType::Number)),
+        ..Default::default()
+    }
+}
+
+fn counter_field(name: &str, description: &str) -> PropertyField {
+    PropertyField {
+        description: description.to_string(),
+        name: name.to_string(),
+        property_type: PropertyType::Number,
+        ..Default::default()
+    }
+}
+
+// The passive checks don't have an interval, they only count the proxied requests
+fn healthy_fields(is_active: bool) -> Vec<PropertyField> {
+    let mut fields = vec![
+        http_statuses_field("HTTP statuses of a healthy node."),
+        counter_field(
+            "successes",
+            "Number of successes to mark the node as healthy.",
+        ),
+    ];
+
+    if is_active {
+        fields.insert(
+            0,
+            counter_field(
+                "interval",
+                "Interval in seconds of the checks of the healthy nodes.",
+            ),
+        );
+    }
+
+    fields
+}
+
+fn unhealthy_fields(is_active: bool) -> Vec<PropertyField> {
+    let mut fields = vec![
+        http_statuses_field("HTTP statuses of an unhealthy node."),
+        counter_field(
+            "http_failures",
+            "Number of HTTP failures to mark the node as unhealthy.",
+        ),
+        counter_field(
+            "tcp_failures",
+            "Number of TCP failures to mark the node as unhealthy.",
+        ),
+        counter_field(
+            "timeouts",
+            "Number of timeouts to mark the node as unhealthy.",
+        ),
+    ];
+
+    if is_active {
+        fields.insert(
+            0,
+            counter_field(
+                "interval",
+                "Interval in seconds of the checks of the unhealthy nodes.",
+            ),
+        );
+    }
+
+    fields
+}
+
+fn active_check_fields() -> Vec<PropertyField> {
+    vec![
+        check_type_field(),
+        counter_field("timeout", "Timeout in seconds of a check."),
+        counter_field("concurrency", "Number of nodes checked at the same time."),
+        PropertyField {
+            description: "Host of the HTTP checks.".to_string(),
+            name: "host".to_string(),
+            ..Default::default()
+        },
+        counter_field(
+            "port",
+            "Port of the checks, instead of the port of the node.",
+        ),
+        PropertyField {
+            default_value: Some("/".to_string()),
+            description: "Path of the HTTP checks.".to_string(),
+            name: "http_path".to_string(),
+            ..Default::default()
+        },
+        PropertyField {
+            default_value: Some("true".to_string()),
+            description: "Verifies the certificate of the HTTPS checks.".to_string(),
+            name: "https_verify_certificate".to_string(),
+            property_type: PropertyType::Boolean,
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Headers of the HTTP checks, like \"User-Agent: curl\".".to_string(),
+            name: "req_headers".to_string(),
+            property_type: PropertyType::List(Box::new(PropertyType::String)),
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Thresholds to mark a node as healthy.".to_string(),
+            name: "healthy".to_string(),
+            property_type: PropertyType::Object(healthy_fields(true)),
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Thresholds to mark a node as unhealthy.".to_string(),
+            name: "unhealthy".to_string(),
+            property_type: PropertyType::Object(unhealthy_fields(true)),
+            ..Default::default()
+        },
+    ]
+}
+
+fn passive_check_fields() -> Vec<PropertyField> {
+    vec![
+        check_type_field(),
+        PropertyField {
+            description: "Thresholds to mark a node as healthy.".to_string(),
+            name: "healthy".to_string(),
+            property_type: PropertyType::Object(healthy_fields(false)),
+            ..Default::default()
+        },
+        PropertyField {
+            description: "Thresholds to mark a node as unhealthy.".to_string(),
+            name: "unhealthy".to_string(),
+            property_type: PropertyType::Object(unhealthy_fields(false)),
+            ..Default::default()
+        },
+    ]
+}
+
 impl EntityTrait for UpstreamEntity {
     fn create(&self) -> Result<ProxyFetchOpts, String> {
+        UpstreamConfig::from_map(&self.parsed.get_cloned())?.validate()?;
+
         let (id, data) = self.get_common_parsed_values();
         let (uri, method) = EntityValue::common_create(Upstream::API_PREFIX, id);
 
@@ -34,6 +225,8 @@ impl EntityTrait for UpstreamEntity {
     }
 
     fn update(&self) -> Result<ProxyFetchOpts, String> {
+        UpstreamConfig::from_map(&self.parsed.get_cloned())?.validate()?;
+
         let (id, data) = self.get_common_parsed_values();
         let (uri, method) = EntityValue::common_update(Upstream::API_PREFIX, id);
 
@@ -63,14 +256,54 @@ impl EntityTrait for UpstreamEntity {
                     "Load balancing algorithm to be used, and the default value is roundrobin."
                         .to_string(),
                 name: "type".to_string(),
+                property_type: PropertyType::Enum(UpstreamType::variants()),
                 ..Default::default()
             },
             EntityFields {
+                description: "IP addresses (with optional ports) of the Upstream nodes represented as a hash table or an array. Can't be used with service_name.".to_string(),
                 name: "nodes".to_string(),
                 example: Some(
-                    r#"{"web-server-1:80": 1}"#.to_string()
+                    r#"{"host": "web-server-1", "port": 80, "weight": 1}"#.to_string()
                 ),
-                property_type: PropertyType::JSON,
+                property_type: PropertyType::List(Box::new(PropertyType::Object(node_fields()))),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Only valid if the type is chash. Supports Nginx variables (vars), custom headers (header), cookie and consumer.".to_string(),
+                name: "hash_on".to_string(),
+                property_type: PropertyType::Enum(HashOn::variants()),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Only valid if the type is chash. Finds the corresponding node id according to hash_on and key.".to_string(),
+                name: "key".to_string(),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Configures the parameters for the health check.".to_string(),
+                example: Some(r#"{"active": {"type": "http", "http_path": "/status", "healthy": {"interval": 2, "successes": 1}, "unhealthy": {"interval": 1, "http_failures": 2}}}"#.to_string()),
+                name: "checks".to_string(),
+                property_type: PropertyType::Object(vec![
+                    PropertyField {
+                        description: "Probes the nodes periodically. Required to enable the health checks.".to_string(),
+                        is_required: Required::True,
+                        name: "active".to_string(),
+                        property_type: PropertyType::Object(active_check_fields()),
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Checks the health of the nodes from the proxied requests.".to_string(),
+                        name: "passive".to_string(),
+                        property_type: PropertyType::Object(passive_check_fields()),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Sets the number of retries while passing the request to Upstream using the underlying Nginx mechanism.".to_string(),
+                name: "retries".to_string(),
+                property_type: PropertyType::Number,
                 ..Default::default()
             },
             EntityFields {
@@ -93,7 +326,29 @@ impl EntityTrait for UpstreamEntity {
                 description: "Sets the timeout (in seconds) for connecting to, and sending and receiving messages to and from the Upstream.".to_string(),
                 example: Some(r#"{"connect": 0.5,"send": 0.5,"read": 0.5}"#.to_string()),
                 name: "timeout".to_string(),
-                property_type: PropertyType::JSON,
+                property_type: PropertyType::Object(vec![
+                    PropertyField {
+                        description: "Timeout for connecting to the Upstream.".to_string(),
+                        is_required: Required::True,
+                        name: "connect".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Timeout for sending messages to the Upstream.".to_string(),
+                        is_required: Required::True,
+                        name: "send".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Timeout for receiving messages from the Upstream.".to_string(),
+                        is_required: Required::True,
+                        name: "read".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                ]),
                 ..Default::default()
             },
             EntityFields {
@@ -107,12 +362,556 @@ impl EntityTrait for UpstreamEntity {
                 ]),
                 ..Default::default()
             },
+            EntityFields {
+                description: "Configures the host when the request is forwarded to the upstream. pass keeps the client's host, node uses the host of the node and rewrite uses upstream_host.".to_string(),
+                name: "pass_host".to_string(),
+                property_type: PropertyType::Enum(PassHost::variants()),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Specifies the host of the Upstream request. This is only valid if the pass_host is set to rewrite.".to_string(),
+                name: "upstream_host".to_string(),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Sets a separate keepalive pool for the Upstream. Overrides the keepalive configuration of Nginx.".to_string(),
+                example: Some(r#"{"size": 320, "idle_timeout": 60, "requests": 1000}"#.to_string()),
+                name: "keepalive_pool".to_string(),
+                property_type: PropertyType::Object(vec![
+                    PropertyField {
+                        default_value: Some("320".to_string()),
+                        description: "Maximum number of idle connections kept in the pool.".to_string(),
+                        name: "size".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        default_value: Some("60".to_string()),
+                        description: "Timeout in seconds of the idle connections.".to_string(),
+                        name: "idle_timeout".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        default_value: Some("1000".to_string()),
+                        description: "Maximum number of requests served by a connection before closing it.".to_string(),
+                        name: "requests".to_string(),
+                        property_type: PropertyType::Number,
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
+            EntityFields {
+                description: "Sets the client certificate while connecting to a TLS Upstream. Use either client_cert and client_key, or client_cert_id referencing an SSL object.".to_string(),
+                example: Some(r#"{"client_cert_id": "1"}"#.to_string()),
+                name: "tls".to_string(),
+                property_type: PropertyType::Object(vec![
+                    PropertyField {
+                        description: "Client certificate in PEM format.".to_string(),
+                        name: "client_cert".to_string(),
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Private key of the client certificate in PEM format.".to_string(),
+                        name: "client_key".to_string(),
+                        sensitive: true,
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Id of an SSL object with the client certificate.".to_string(),
+                        name: "client_cert_id".to_string(),
+                        ..Default::default()
+                    },
+                    PropertyField {
+                        description: "Verifies the certificate of the Upstream.".to_string(),
+                        name: "verify".to_string(),
+                        property_type: PropertyType::Boolean,
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
             EntityFields {
                 description: "Attributes of the Upstream specified as key-value pairs.".to_string(),
                 name: "labels".to_string(),
-                property_type: PropertyType::JSON,
+                property_type: PropertyType::Map(Box::new(PropertyType::String)),
                 ..Default::default()
             },
         ]
     }
 }
+
+derive_common_default! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum UpstreamType {
+    #[default]
+    Roundrobin,
+    Chash,
+    Ewma,
+    LeastConn,
+}}
+
+impl UpstreamType {
+    pub fn variants() -> Vec<String> {
+        ["roundrobin", "chash", "ewma", "least_conn"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+}
+
+derive_common_default! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum HashOn {
+    #[default]
+    Vars,
+    Header,
+    Cookie,
+    Consumer,
+    VarsCombinations,
+}}
+
+impl HashOn {
+    pub fn variants() -> Vec<String> {
+        ["vars", "header", "cookie", "consumer", "vars_combinations"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+}
+
+derive_common_default! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum PassHost {
+    #[default]
+    Pass,
+    Node,
+    Rewrite,
+}}
+
+impl PassHost {
+    pub fn variants() -> Vec<String> {
+        ["pass", "node", "rewrite"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+}
+
+derive_common_default! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckType {
+    #[default]
+    Http,
+    Https,
+    Tcp,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct UpstreamNode {
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    pub weight: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}}
+
+derive_common! {
+#[derive(TS)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum UpstreamNodes {
+    Map(HashMap<String, u32>),
+    List(Vec<UpstreamNode>),
+}}
+
+impl UpstreamNodes {
+    fn parse_address(address: &str) -> Result<(String, Option<u16>), String> {
+        // IPv6 addresses are wrapped in brackets when they include a port: `[::1]:80`
+        let (host, port) = if let Some(rest) = address.strip_prefix('[') {
+            let (host, rest) = rest
+                .split_once(']')
+                .ok_or(format!("nodes: invalid address '{}'", address))?;
+            (format!("[{}]", host), rest.strip_prefix(':'))
+        } else if address.matches(':').count() == 1 {
+            let (host, port) = address.split_once(':').unwrap();
+            (host.to_string(), Some(port))
+        } else {
+            (address.to_string(), None)
+        };
+
+        let port = port
+            .map(|p| {
+                p.parse::<u16>()
+                    .map_err(|_| format!("nodes: invalid port in '{}'", address))
+            })
+            .transpose()?;
+
+        Ok((host, port))
+    }
+
+    /// Returns the nodes in the array form, converting them if they use the hash table form
+    pub fn to_list(&self) -> Result<Vec<UpstreamNode>, String> {
+        match self {
+            Self::List(list) => Ok(list.clone()),
+            Self::Map(map) => {
+                let mut list = map
+                    .iter()
+                    .map(|(address, weight)| {
+                        let (host, port) = Self::parse_address(address)?;
+
+                        Ok(UpstreamNode {
+                            host,
+                            port,
+                            weight: *weight,
+                            priority: None,
+                        })
+                    })
+                    .collect::<Result<Vec<UpstreamNode>, String>>()?;
+
+                list.sort_by(|a, b| (&a.host, a.port).cmp(&(&b.host, b.port)));
+
+                Ok(list)
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for node in self.to_list()? {
+            if node.host.is_empty() {
+                return Err("nodes: host can't be empty".to_string());
+            }
+
+            if node.port == Some(0) {
+                return Err(format!("nodes: invalid port 0 for '{}'", node.host));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct UpstreamTimeout {
+    pub connect: f64,
+    pub send: f64,
+    pub read: f64,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct HealthCheckHealthy {
+    pub interval: Option<u32>,
+    pub http_statuses: Option<Vec<u16>>,
+    pub successes: Option<u32>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct HealthCheckUnhealthy {
+    pub interval: Option<u32>,
+    pub http_statuses: Option<Vec<u16>>,
+    pub http_failures: Option<u32>,
+    pub tcp_failures: Option<u32>,
+    pub timeouts: Option<u32>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct ActiveHealthCheck {
+    #[serde(rename = "type")]
+    pub check_type: Option<HealthCheckType>,
+    pub timeout: Option<f64>,
+    pub concurrency: Option<u32>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub http_path: Option<String>,
+    pub https_verify_certificate: Option<bool>,
+    pub req_headers: Option<Vec<String>>,
+    pub healthy: Option<HealthCheckHealthy>,
+    pub unhealthy: Option<HealthCheckUnhealthy>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct PassiveHealthCheck {
+    #[serde(rename = "type")]
+    pub check_type: Option<HealthCheckType>,
+    pub healthy: Option<HealthCheckHealthy>,
+    pub unhealthy: Option<HealthCheckUnhealthy>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct HealthCheck {
+    pub active: Option<ActiveHealthCheck>,
+    pub passive: Option<PassiveHealthCheck>,
+}}
+
+impl HealthCheck {
+    fn validate(&self) -> Result<(), String> {
+        let active = self
+            .active
+            .as_ref()
+            .ok_or("checks.active: is required, passive checks can't be used alone")?;
+
+        if active.port == Some(0) {
+            return Err("checks.active.port: must be between 1 and 65535".to_string());
+        }
+
+        if let Some(timeout) = active.timeout {
+            if timeout <= 0.0 {
+                return Err("checks.active.timeout: must be greater than 0".to_string());
+            }
+        }
+
+        let statuses = [
+            active
+                .healthy
+                .as_ref()
+                .and_then(|h| h.http_statuses.as_ref()),
+            active
+                .unhealthy
+                .as_ref()
+                .and_then(|h| h.http_statuses.as_ref()),
+            self.passive
+                .as_ref()
+                .and_then(|p| p.healthy.as_ref())
+                .and_then(|h| h.http_statuses.as_ref()),
+            self.passive
+                .as_ref()
+                .and_then(|p| p.unhealthy.as_ref())
+                .and_then(|h| h.http_statuses.as_ref()),
+        ];
+
+        for status in statuses.iter().flatten().flat_map(|s| s.iter()) {
+            if !(200..=599).contains(status) {
+                return Err(format!(
+                    "checks: invalid HTTP status {}, it must be between 200 and 599",
+                    status
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct KeepalivePool {
+    pub size: Option<u32>,
+    pub idle_timeout: Option<f64>,
+    pub requests: Option<u32>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct UpstreamTls {
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+    pub client_cert_id: Option<String>,
+    pub verify: Option<bool>,
+}}
+
+derive_common_default! {
+#[derive(TS)]
+#[ts(export)]
+pub struct UpstreamConfig {
+    #[serde(rename = "type")]
+    pub upstream_type: Option<UpstreamType>,
+    pub nodes: Option<UpstreamNodes>,
+    pub service_name: Option<String>,
+    pub discovery_type: Option<String>,
+    pub hash_on: Option<HashOn>,
+    pub key: Option<String>,
+    pub checks: Option<HealthCheck>,
+    pub retries: Option<u32>,
+    pub retry_timeout: Option<f64>,
+    pub timeout: Option<UpstreamTimeout>,
+    pub pass_host: Option<PassHost>,
+    pub upstream_host: Option<String>,
+    pub keepalive_pool: Option<KeepalivePool>,
+    pub tls: Option<UpstreamTls>,
+}}
+
+impl UpstreamConfig {
+    /// Parses the typed configuration, ignoring the fields that are not part of it (e.g. `name`)
+    pub fn from_map(map: &HashMap<String, Value>) -> Result<Self, String> {
+        let value = serde_json::to_value(map).map_err(|e| e.to_string())?;
+
+        Self::from_value(&value)
+    }
+
+    pub fn from_value(value: &Value) -> Result<Self, String> {
+        serde_json::from_value(value.clone()).map_err(|e| format!("Invalid upstream: {}", e))
+    }
+
+    /// Validates the inline `upstream` of an entity (e.g. a Route or a Service) when present
+    pub fn validate_inline(values: &HashMap<String, Value>) -> Result<(), String> {
+        match values.get("upstream") {
+            Some(upstream) if !upstream.is_null() => Self::from_value(upstream)?.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match (&self.nodes, &self.service_name) {
+            (None, None) => {
+                return Err("nodes: is required when service_name is missing".to_string());
+            }
+            (Some(_), Some(_)) => {
+                return Err("nodes: can't be used together with service_name".to_string());
+            }
+            (Some(nodes), None) => nodes.validate()?,
+            (None, Some(_)) => {
+                if self.discovery_type.is_none() {
+                    return Err("discovery_type: is required when using service_name".to_string());
+                }
+            }
+        }
+
+        let is_chash = self.upstream_type == Some(UpstreamType::Chash);
+
+        // APISIX stores the default `vars` in every upstream, whatever the type
+        if !is_chash && self.hash_on.as_ref().is_some_and(|h| *h != HashOn::Vars) {
+            return Err("hash_on: requires the type to be chash".to_string());
+        }
+
+        if !is_chash && self.key.is_some() {
+            return Err("key: requires the type to be chash".to_string());
+        }
+
+        if is_chash
+            && self.hash_on != Some(HashOn::Consumer)
+            && self.key.as_ref().is_none_or(|k| k.is_empty())
+        {
+            return Err("key: is required when the type is chash".to_string());
+        }
+
+        if self.pass_host == Some(PassHost::Rewrite)
+            && self.upstream_host.as_ref().is_none_or(|h| h.is_empty())
+        {
+            return Err("upstream_host: is required when pass_host is rewrite".to_string());
+        }
+
+        if let Some(timeout) = &self.timeout {
+            if timeout.connect <= 0.0 || timeout.send <= 0.0 || timeout.read <= 0.0 {
+                return Err("timeout: connect, send and read must be greater than 0".to_string());
+            }
+        }
+
+        if let Some(checks) = &self.checks {
+            checks.validate()?;
+        }
+
+        if let Some(pool) = &self.keepalive_pool {
+            if pool.size == Some(0) || pool.requests == Some(0) {
+                return Err("keepalive_pool: size and requests must be at least 1".to_string());
+            }
+        }
+
+        if let Some(tls) = &self.tls {
+            if tls.client_cert.is_some() != tls.client_key.is_some() {
+                return Err("tls: client_cert and client_key must be set together".to_string());
+            }
+
+            if tls.client_cert_id.is_some() && tls.client_cert.is_some() {
+                return Err("tls: client_cert_id can't be used with client_cert".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apisix::validation::{validate_fields, FieldError};
+    use serde_json::json;
+
+    fn validate(value: Value) -> Result<(), String> {
+        UpstreamConfig::from_value(&value)?.validate()
+    }
+
+    #[test]
+    fn test_default_hash_on_without_chash() {
+        // As returned by APISIX for a roundrobin upstream
+        let upstream = json!({
+            "type": "roundrobin",
+            "hash_on": "vars",
+            "nodes": { "127.0.0.1:80": 1 },
+        });
+
+        assert_eq!(validate(upstream), Ok(()));
+    }
+
+    #[test]
+    fn test_hash_fields_require_chash() {
+        let nodes = json!({ "127.0.0.1:80": 1 });
+
+        assert_eq!(
+            validate(json!({ "type": "ewma", "hash_on": "header", "nodes": nodes })),
+            Err("hash_on: requires the type to be chash".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "type": "least_conn", "key": "remote_addr", "nodes": nodes })),
+            Err("key: requires the type to be chash".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "type": "chash", "hash_on": "vars", "nodes": nodes })),
+            Err("key: is required when the type is chash".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "type": "chash", "key": "remote_addr", "nodes": nodes })),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        let value = json!({
+            "nodes": [{ "host": "127.0.0.1", "weight": 1 }, { "port": "80", "weight": 1 }],
+            "checks": { "active": { "healthy": { "interval": "2" } }, "passive": {} },
+        });
+        let mut errors = vec![];
+
+        validate_fields(
+            &UpstreamEntity::value_fields(),
+            value.as_object().unwrap(),
+            "",
+            &mut errors,
+        );
+
+        assert_eq!(
+            FieldError::join(&errors),
+            FieldError::join(&[
+                FieldError::new("nodes[1].host", "is required"),
+                FieldError::new("nodes[1].port", "must be a number"),
+                FieldError::new("checks.active.healthy.interval", "must be a number"),
+            ])
+        );
+    }
+}
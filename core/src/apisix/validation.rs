@@ -0,0 +1,462 @@
+use super::{
+    base::{PropertyField, PropertyType, Required},
+    common::EntityFields,
+    plugins::{
+        common::{PluginEntities, PluginOption},
+        ip_restriction::is_ip_or_cidr,
+        registry::PluginRegistry,
+    },
+};
+use crate::macros::derive_common;
+use serde_json::{Map, Value};
+use ts_rs::TS;
+
+derive_common! {
+#[derive(TS, PartialEq, Eq)]
+#[ts(export)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}}
+
+impl FieldError {
+    pub fn new(field: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn join(errors: &[FieldError]) -> String {
+        errors
+            .iter()
+            .map(|e| format!("{}: {}", e.field, e.message))
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
+
+pub trait FieldDefinition {
+    fn field_name(&self) -> &str;
+    fn field_required(&self) -> &Required;
+    fn field_type(&self) -> &PropertyType;
+}
+
+macro_rules! impl_field_definition {
+    ($struct_name: ident) => {
+        impl FieldDefinition for $struct_name {
+            fn field_name(&self) -> &str {
+                &self.name
+            }
+            fn field_required(&self) -> &Required {
+                &self.is_required
+            }
+            fn field_type(&self) -> &PropertyType {
+                &self.property_type
+            }
+        }
+    };
+}
+
+impl_field_definition!(EntityFields);
+impl_field_definition!(PluginOption);
+impl_field_definition!(PropertyField);
+
+pub fn join_path(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
+}
+
+fn is_missing(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => true,
+        Some(Value::String(s)) => s.is_empty(),
+        _ => false,
+    }
+}
+
+pub fn validate_value(
+    property_type: &PropertyType,
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    match property_type {
+        PropertyType::Boolean if !value.is_boolean() => {
+            errors.push(FieldError::new(path, "must be a boolean"));
+        }
+        PropertyType::Number if !value.is_number() => {
+            errors.push(FieldError::new(path, "must be a number"));
+        }
+        PropertyType::String | PropertyType::Code(_) if !value.is_string() => {
+            errors.push(FieldError::new(path, "must be a string"));
+        }
+        PropertyType::Enum(options) => {
+            let is_valid = value
+                .as_str()
+                .map(|v| options.iter().any(|o| o == v))
+                .unwrap_or(false);
+
+            if !is_valid {
+                let message = format!("must be one of: {}", options.join(", "));
+                errors.push(FieldError::new(path, &message));
+            }
+        }
+        PropertyType::List(item_type) => match value.as_array() {
+            Some(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    validate_value(item_type, item, &format!("{}[{}]", path, idx), errors);
+                }
+            }
+            None => errors.push(FieldError::new(path, "must be a list")),
+        },
+        PropertyType::Map(value_type) => match value.as_object() {
+            Some(map) => {
+                for (key, item) in map.iter() {
+                    validate_value(value_type, item, &join_path(path, key), errors);
+                }
+            }
+            None => errors.push(FieldError::new(path, "must be an object")),
+        },
+        PropertyType::Object(fields) => match value.as_object() {
+            Some(map) => validate_fields(fields, map, path, errors),
+            None => errors.push(FieldError::new(path, "must be an object")),
+        },
+        PropertyType::Plugins if !value.is_object() => {
+            errors.push(FieldError::new(path, "must be an object"));
+        }
+        _ => {}
+    }
+}
+
+// Unknown keys are not reported, they are passed to APISIX as they are
+pub fn validate_fields<F: FieldDefinition>(
+    fields: &[F],
+    values: &Map<String, Value>,
+    prefix: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    for field in fields {
+        let name = field.field_name();
+        let path = join_path(prefix, name);
+        let value = values.get(name);
+
+        if is_missing(value) {
+            let is_required = match field.field_required() {
+                Required::True => true,
+                Required::False => false,
+                Required::TrueIfOtherMissing(others) => {
+                    others.iter().all(|other| is_missing(values.get(other)))
+                }
+            };
+
+            if is_required {
+                errors.push(FieldError::new(&path, "is required"));
+            }
+
+            continue;
+        }
+
+        validate_value(field.field_type(), value.unwrap(), &path, errors);
+    }
+}
+
+pub fn validate_plugins(
+    plugins: &Map<String, Value>,
+    entity: &PluginEntities,
+    registry: &PluginRegistry,
+    prefix: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    for (plugin_name, plugin_config) in plugins.iter() {
+        let path = join_path(prefix, plugin_name);
+
+        // Plugins without a definition can still be added as raw JSON
+        let Some(definition) = registry.get(plugin_name, entity) else {
+            continue;
+        };
+
+        definition.validate(plugin_config, &path, errors);
+    }
+}
+
+// Problem in an option of a plugin config, found by the checks that the option types can't express
+pub struct PluginConfigIssue {
+    pub option: &'static str,
+    pub index: Option<usize>,
+    pub message: String,
+}
+
+impl PluginConfigIssue {
+    pub fn to_field_error(&self, path: &str) -> FieldError {
+        let field = match self.index {
+            Some(idx) => format!("{}[{}]", join_path(path, self.option), idx),
+            None => join_path(path, self.option),
+        };
+
+        FieldError::new(&field, &self.message)
+    }
+
+    pub fn to_pointer_error(&self, pointer: &str) -> FieldError {
+        let field = match self.index {
+            Some(idx) => format!("{}/{}/{}", pointer, self.option, idx),
+            None => format!("{}/{}", pointer, self.option),
+        };
+
+        FieldError::new(&field, &self.message)
+    }
+}
+
+fn ip_list_issues(config: &Value, options: &[&'static str], issues: &mut Vec<PluginConfigIssue>) {
+    for option in options {
+        let entries = config.get(option).and_then(|e| e.as_array());
+
+        for (idx, entry) in entries.into_iter().flatten().enumerate() {
+            if !entry.as_str().is_some_and(is_ip_or_cidr) {
+                issues.push(PluginConfigIssue {
+                    option,
+                    index: Some(idx),
+                    message: "is not a valid IP address or CIDR range".to_string(),
+                });
+            }
+        }
+    }
+}
+
+// APISIX refuses the wildcards together with credentials
+fn cors_issues(config: &Value, issues: &mut Vec<PluginConfigIssue>) {
+    if config.get("allow_credential").and_then(|c| c.as_bool()) != Some(true) {
+        return;
+    }
+
+    for option in [
+        "allow_origins",
+        "allow_methods",
+        "allow_headers",
+        "expose_headers",
+    ] {
+        if config.get(option).and_then(|v| v.as_str()) == Some("*") {
+            issues.push(PluginConfigIssue {
+                option,
+                index: None,
+                message: "can't be * when allow_credential is true".to_string(),
+            });
+        }
+    }
+}
+
+pub fn plugin_config_issues(plugin_name: &str, config: &Value) -> Vec<PluginConfigIssue> {
+    let mut issues = vec![];
+
+    match plugin_name {
+        "cors" => cors_issues(config, &mut issues),
+        "ip-restriction" => ip_list_issues(config, &["whitelist", "blacklist"], &mut issues),
+        "real-ip" => ip_list_issues(config, &["trusted_addresses"], &mut issues),
+        _ => {}
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ip_list_issues() {
+        let issues = plugin_config_issues(
+            "ip-restriction",
+            &json!({
+                "whitelist": ["10.0.0.0/8", "10.0.0.0/33", "::1"],
+                "blacklist": ["fe80::/129"],
+            }),
+        );
+        let fields: Vec<String> = issues
+            .iter()
+            .map(|i| i.to_field_error("plugins.ip-restriction").field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "plugins.ip-restriction.whitelist[1]",
+                "plugins.ip-restriction.blacklist[0]",
+            ]
+        );
+
+        let issues = plugin_config_issues(
+            "real-ip",
+            &json!({ "trusted_addresses": ["127.0.0.1", 1, "localhost"] }),
+        );
+        let pointers: Vec<String> = issues
+            .iter()
+            .map(|i| i.to_pointer_error("/plugins/real-ip").field)
+            .collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/plugins/real-ip/trusted_addresses/1",
+                "/plugins/real-ip/trusted_addresses/2",
+            ]
+        );
+
+        assert!(plugin_config_issues("real-ip", &json!({ "source": "http_x_real_ip" })).is_empty());
+    }
+
+    fn field(name: &str, property_type: PropertyType, is_required: Required) -> PropertyField {
+        PropertyField {
+            is_required,
+            name: name.to_string(),
+            property_type,
+            ..Default::default()
+        }
+    }
+
+    fn field_errors<F: FieldDefinition>(fields: &[F], value: Value) -> Vec<(String, String)> {
+        let mut errors = vec![];
+
+        validate_fields(fields, value.as_object().unwrap(), "", &mut errors);
+
+        errors.into_iter().map(|e| (e.field, e.message)).collect()
+    }
+
+    fn expected(errors: &[(&str, &str)]) -> Vec<(String, String)> {
+        errors
+            .iter()
+            .map(|(field, message)| (field.to_string(), message.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_required_if_other_missing() {
+        let fields = [
+            field(
+                "uri",
+                PropertyType::String,
+                Required::TrueIfOtherMissing(vec!["uris".to_string()]),
+            ),
+            field(
+                "uris",
+                PropertyType::List(Box::new(PropertyType::String)),
+                Required::TrueIfOtherMissing(vec!["uri".to_string()]),
+            ),
+        ];
+        let missing = [("uri", "is required"), ("uris", "is required")];
+
+        let cases = [
+            (json!({}), expected(&missing)),
+            // The empty strings are missing values
+            (json!({ "uri": "" }), expected(&missing)),
+            (json!({ "uri": "/foo" }), vec![]),
+            (json!({ "uris": ["/foo"] }), vec![]),
+            (json!({ "uri": "/foo", "uris": ["/bar"] }), vec![]),
+        ];
+
+        for (value, errors) in cases {
+            assert_eq!(field_errors(&fields, value.clone()), errors, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_enum_values() {
+        let fields = [field(
+            "scheme",
+            PropertyType::Enum(vec!["http".to_string(), "https".to_string()]),
+            Required::False,
+        )];
+        let invalid = [("scheme", "must be one of: http, https")];
+
+        let cases = [
+            (json!({ "scheme": "https" }), vec![]),
+            (json!({}), vec![]),
+            (json!({ "scheme": "grpc" }), expected(&invalid)),
+            (json!({ "scheme": "HTTP" }), expected(&invalid)),
+            (json!({ "scheme": 1 }), expected(&invalid)),
+        ];
+
+        for (value, errors) in cases {
+            assert_eq!(field_errors(&fields, value.clone()), errors, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_nested_locations() {
+        let node = PropertyType::Object(vec![
+            field("host", PropertyType::String, Required::True),
+            field("weight", PropertyType::Number, Required::False),
+        ]);
+        let fields = [
+            field(
+                "timeout",
+                PropertyType::Object(vec![field("connect", PropertyType::Number, Required::True)]),
+                Required::False,
+            ),
+            field(
+                "labels",
+                PropertyType::Map(Box::new(PropertyType::String)),
+                Required::False,
+            ),
+            field(
+                "nodes",
+                PropertyType::List(Box::new(node.clone())),
+                Required::False,
+            ),
+            field("pools", PropertyType::Map(Box::new(node)), Required::False),
+        ];
+
+        let cases = [
+            (
+                json!({ "timeout": {} }),
+                expected(&[("timeout.connect", "is required")]),
+            ),
+            (
+                json!({ "timeout": { "connect": "1s" } }),
+                expected(&[("timeout.connect", "must be a number")]),
+            ),
+            (
+                json!({ "timeout": 1 }),
+                expected(&[("timeout", "must be an object")]),
+            ),
+            (
+                json!({ "labels": { "env": "prod", "build": 16 } }),
+                expected(&[("labels.build", "must be a string")]),
+            ),
+            (
+                json!({ "labels": [] }),
+                expected(&[("labels", "must be an object")]),
+            ),
+            (
+                json!({ "nodes": [{ "host": "a" }, { "weight": "1" }] }),
+                expected(&[
+                    ("nodes[1].host", "is required"),
+                    ("nodes[1].weight", "must be a number"),
+                ]),
+            ),
+            (
+                json!({ "nodes": {} }),
+                expected(&[("nodes", "must be a list")]),
+            ),
+            (
+                json!({ "pools": { "main": { "host": 1 } } }),
+                expected(&[("pools.main.host", "must be a string")]),
+            ),
+        ];
+
+        for (value, errors) in cases {
+            assert_eq!(field_errors(&fields, value.clone()), errors, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_cors_issues() {
+        let config =
+            json!({ "allow_credential": true, "allow_origins": "*", "allow_methods": "GET" });
+        assert_eq!(plugin_config_issues("cors", &config).len(), 1);
+
+        let config = json!({ "allow_credential": false, "allow_origins": "*" });
+        assert!(plugin_config_issues("cors", &config).is_empty());
+    }
+}
@@ -1,5 +1,17 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
 use apisix_admin_panel_core::proxy::{ProxyFetchMethod, ProxyFetchOpts};
 
+// The version is detected when loading the dashboard, so an unreachable APISIX can't stall it
+const VERSION_TIMEOUT: Duration = Duration::from_secs(2);
+const VERSION_TTL: Duration = Duration::from_secs(10 * 60);
+// Retried sooner, the APISIX may be starting
+const FAILED_VERSION_TTL: Duration = Duration::from_secs(60);
+
 pub struct AdminApiHandler {
     pub api_key: String,
     pub url: String,
@@ -49,4 +61,54 @@ impl AdminApiHandler {
 
         res.text().await.map_err(|_| common_error)
     }
+
+    // Every admin API response has a `Server: APISIX/x.y.z` header
+    async fn fetch_version(&self) -> Option<String> {
+        let url = format!("{}/apisix/admin/plugins/list", self.url.clone());
+        let res = reqwest::Client::builder()
+            .timeout(VERSION_TIMEOUT)
+            .build()
+            .ok()?
+            .get(&url)
+            .header("X-API-KEY", self.api_key.clone())
+            .send()
+            .await
+            .ok()?;
+        let server = res.headers().get("server")?.to_str().ok()?;
+
+        server.strip_prefix("APISIX/").map(|v| v.to_string())
+    }
+}
+
+/// Versions detected for each admin API url, including the failed detections
+#[derive(Default)]
+pub struct VersionCache(Mutex<HashMap<String, (Instant, Option<String>)>>);
+
+impl VersionCache {
+    pub async fn get(&self, handler: &AdminApiHandler) -> Option<String> {
+        let cached = self
+            .0
+            .lock()
+            .ok()
+            .and_then(|versions| versions.get(&handler.url).cloned());
+
+        if let Some((fetched_at, version)) = cached {
+            let ttl = match version {
+                Some(_) => VERSION_TTL,
+                None => FAILED_VERSION_TTL,
+            };
+
+            if fetched_at.elapsed() < ttl {
+                return version;
+            }
+        }
+
+        let version = handler.fetch_version().await;
+
+        if let Ok(mut versions) = self.0.lock() {
+            versions.insert(handler.url.clone(), (Instant::now(), version.clone()));
+        }
+
+        version
+    }
 }
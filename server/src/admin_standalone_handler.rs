@@ -1,8 +1,9 @@
 use actix_files::NamedFile;
 use apisix_admin_panel_core::{
     apisix::{
-        common::prelude::*, consumer_groups::ConsumerGroup, consumers::Consumer, routes::Route,
-        secrets::Secret, services::Service, stream_routes::StreamRoute, upstreams::Upstream,
+        common::prelude::*, consumer_groups::ConsumerGroup, consumers::Consumer,
+        promotion::PromotionEntity, routes::Route, secrets::Secret, services::Service,
+        stream_routes::StreamRoute, upstreams::Upstream,
     },
     proxy::{ProxyFetchMethod, ProxyFetchOpts},
 };
@@ -50,6 +51,13 @@ impl AdminStandaloneHandler {
             return self
                 .handle_items("consumer-groups", opts, map_content)
                 .await;
+        } else if opts
+            .uri
+            .starts_with(PromotionEntity::PluginConfig.api_prefix())
+        {
+            return self.handle_items("plugin_configs", opts, map_content).await;
+        } else if opts.uri.starts_with(PromotionEntity::Ssl.api_prefix()) {
+            return self.handle_items("ssls", opts, map_content).await;
         }
 
         let json_str = serde_json::to_string(&map_content)
@@ -97,35 +105,31 @@ impl AdminStandaloneHandler {
 
         let mut items_raw: Vec<HashMap<String, Value>> = serde_json::from_value(items).unwrap();
 
+        // The ids are stored, so the items can be found when they are updated
+        for (item_raw_idx, item_raw) in items_raw.iter_mut().enumerate() {
+            if item_raw.get(id_key).is_none() {
+                item_raw.insert(id_key.to_string(), json!(item_raw_idx.to_string()));
+            }
+        }
+
+        // Only the response normalizes the plugins, the stored items keep the null plugins and
+        // their `_meta` untouched when other items are written back
         let items = items_raw
-            .iter_mut()
-            .enumerate()
-            .map(|(item_raw_idx, item_raw)| {
-                let current_plugins = item_raw.get("plugins");
-                if let Some(plugins) = current_plugins {
-                    let mut plugins_map: HashMap<String, Value> =
-                        serde_json::from_value(plugins.clone()).unwrap();
-
-                    for (plugin_name, plugin) in plugins_map.clone().iter() {
-                        if plugin.clone() == Value::Null {
-                            let empty_object: HashMap<String, Value> = HashMap::new();
-                            plugins_map.insert(plugin_name.clone(), json!(empty_object));
+            .iter()
+            .map(|item_raw| {
+                let mut item = item_raw.clone();
+
+                if let Some(Value::Object(plugins)) = item.get_mut("plugins") {
+                    for plugin in plugins.values_mut() {
+                        if plugin.is_null() {
+                            *plugin = json!({});
                         }
                     }
-
-                    item_raw.insert("plugins".to_string(), json!(plugins_map));
                 }
-                let current_id = item_raw.get(id_key);
-                if current_id.is_none() {
-                    let item_id = item_raw_idx.to_string();
-                    item_raw.insert(id_key.to_string(), json!(item_id));
-                }
-
-                let new_route = json!({
-                    "value": item_raw.clone(),
-                });
 
-                new_route
+                json!({
+                    "value": item,
+                })
             })
             .collect::<Vec<Value>>();
 
@@ -0,0 +1,290 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use ts_rs::TS;
+
+use crate::{auth::now_secs, config::Role, csrf::random_token, server_error::RespError};
+
+// Makes the tokens easy to find by secret scanners
+const TOKEN_PREFIX: &str = "apisix_panel_";
+
+// First segment of the admin API uris, like `/routes/1`
+pub const ENTITY_TYPES: &[&str] = &[
+    "consumer_groups",
+    "consumers",
+    "global_rules",
+    "plugin_configs",
+    "plugin_metadata",
+    "protos",
+    "routes",
+    "secrets",
+    "services",
+    "ssls",
+    "stream_routes",
+    "upstreams",
+];
+
+// The secret of the token is only returned when it is created
+#[derive(Clone, Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct ApiToken {
+    #[ts(type = "number")]
+    pub created_at: u64,
+    // The tokens of the IdP users expire, since the IdP isn't asked again
+    #[ts(type = "number | null")]
+    pub expires_at: Option<u64>,
+    pub id: String,
+    pub name: String,
+    // Role of the user when the token was created
+    pub role: Role,
+    pub username: String,
+    // Entity types that the token can modify, it can read all of them
+    pub write: Vec<String>,
+}
+
+impl ApiToken {
+    // The uri is normalized like the admin API does, so `..` can't escape the entity type
+    pub fn can_write(&self, uri: &str) -> bool {
+        let url = reqwest::Url::parse(&format!("http://localhost/apisix/admin{uri}"));
+        let entity_type = url.ok().and_then(|url| {
+            url.path()
+                .strip_prefix("/apisix/admin/")
+                .and_then(|p| p.split('/').next())
+                .map(str::to_string)
+        });
+
+        self.role == Role::Admin && entity_type.is_some_and(|e| self.write.contains(&e))
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct StoredApiToken {
+    #[serde(flatten)]
+    token: ApiToken,
+    // SHA-256 of the secret, the tokens are random so they don't need a salt
+    hash: String,
+}
+
+fn hash_secret(secret: &str) -> String {
+    format!("{:x}", Sha256::digest(secret.as_bytes()))
+}
+
+/// Tokens saved in a JSON file, which is rewritten after every change
+pub struct ApiTokenStore {
+    path: String,
+    tokens: Mutex<Vec<StoredApiToken>>,
+}
+
+impl ApiTokenStore {
+    // The file is created with the first token
+    pub fn load(path: &str) -> Result<Self, String> {
+        let tokens = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Invalid API tokens file {path}: {e}"))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(format!("Failed to read the API tokens file {path}: {e}")),
+        };
+
+        Ok(Self {
+            path: path.to_string(),
+            tokens: Mutex::new(tokens),
+        })
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<StoredApiToken>>, RespError> {
+        self.tokens
+            .lock()
+            .map_err(|_| RespError::Custom("Failed to access the API tokens".to_string()))
+    }
+
+    // Written to a temporary file first, so a failed write doesn't lose the tokens
+    fn save(&self, tokens: &[StoredApiToken]) -> Result<(), RespError> {
+        let content = serde_json::to_string_pretty(tokens)
+            .map_err(|_| RespError::Custom("Failed to serialize the API tokens".to_string()))?;
+        let tmp_path = format!("{}.tmp", self.path);
+
+        std::fs::write(&tmp_path, content)
+            .and_then(|_| std::fs::rename(&tmp_path, &self.path))
+            .map_err(|_| RespError::Custom("Failed to save the API tokens".to_string()))
+    }
+
+    pub fn find(&self, secret: &str) -> Option<ApiToken> {
+        let hash = hash_secret(secret);
+
+        self.lock()
+            .ok()?
+            .iter()
+            .find(|t| t.hash == hash)
+            .map(|t| t.token.clone())
+    }
+
+    pub fn list(&self, username: &str) -> Result<Vec<ApiToken>, RespError> {
+        Ok(self
+            .lock()?
+            .iter()
+            .filter(|t| t.token.username == username)
+            .map(|t| t.token.clone())
+            .collect())
+    }
+
+    /// Returns the secret of the new token and its info
+    pub fn create(
+        &self,
+        username: &str,
+        role: Role,
+        name: &str,
+        write: Vec<String>,
+        expires_at: Option<u64>,
+    ) -> Result<(String, ApiToken), RespError> {
+        if name.is_empty() {
+            return Err(RespError::BadRequest(
+                "The token name is required".to_string(),
+            ));
+        }
+
+        if let Some(e) = write.iter().find(|e| !ENTITY_TYPES.contains(&e.as_str())) {
+            return Err(RespError::BadRequest(format!("Unknown entity type: {e}")));
+        }
+
+        // A viewer can't create tokens with more permissions than itself
+        if role != Role::Admin && !write.is_empty() {
+            return Err(RespError::Forbidden);
+        }
+
+        let secret = format!("{TOKEN_PREFIX}{}", random_token());
+        let token = ApiToken {
+            created_at: now_secs(),
+            expires_at,
+            id: random_token()[..12].to_string(),
+            name: name.to_string(),
+            role,
+            username: username.to_string(),
+            write,
+        };
+
+        let mut tokens = self.lock()?;
+        let mut new_tokens = tokens.clone();
+
+        new_tokens.push(StoredApiToken {
+            token: token.clone(),
+            hash: hash_secret(&secret),
+        });
+        self.save(&new_tokens)?;
+        *tokens = new_tokens;
+
+        Ok((secret, token))
+    }
+
+    pub fn revoke(&self, username: &str, id: &str) -> Result<(), RespError> {
+        let mut tokens = self.lock()?;
+        let new_tokens: Vec<_> = tokens
+            .iter()
+            .filter(|t| !(t.token.id == id && t.token.username == username))
+            .cloned()
+            .collect();
+
+        if new_tokens.len() == tokens.len() {
+            return Err(RespError::NotFound(format!("Unknown token: {id}")));
+        }
+
+        self.save(&new_tokens)?;
+        *tokens = new_tokens;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, ResponseError};
+
+    use super::*;
+
+    fn test_store(name: &str) -> ApiTokenStore {
+        let path = std::env::temp_dir().join(format!(
+            "apisix-panel-store-{name}-{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        ApiTokenStore::load(path.to_str().unwrap()).unwrap()
+    }
+
+    fn create(store: &ApiTokenStore, role: Role, write: &[&str]) -> (String, ApiToken) {
+        let write = write.iter().map(|e| e.to_string()).collect();
+
+        store.create("admin", role, "ci", write, None).unwrap()
+    }
+
+    #[test]
+    fn test_token_is_stored_hashed() {
+        let store = test_store("hashed");
+        let (secret, token) = create(&store, Role::Admin, &["routes"]);
+        assert!(secret.starts_with(TOKEN_PREFIX));
+
+        let content = std::fs::read_to_string(&store.path).unwrap();
+        assert!(!content.contains(&secret));
+        assert!(content.contains(&hash_secret(&secret)));
+
+        let reloaded = ApiTokenStore::load(&store.path).unwrap();
+        assert_eq!(reloaded.find(&secret).map(|t| t.id), Some(token.id));
+        assert!(reloaded.find("apisix_panel_other").is_none());
+    }
+
+    #[test]
+    fn test_revoked_token_is_not_found() {
+        let store = test_store("revoked");
+        let (secret, token) = create(&store, Role::Admin, &["routes"]);
+
+        // Only the owner can revoke it
+        let err = store.revoke("other", &token.id).unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::NOT_FOUND);
+
+        store.revoke("admin", &token.id).unwrap();
+        assert!(store.find(&secret).is_none());
+        assert!(ApiTokenStore::load(&store.path)
+            .unwrap()
+            .find(&secret)
+            .is_none());
+    }
+
+    fn create_error(store: &ApiTokenStore, role: Role, name: &str, write: &[&str]) -> RespError {
+        let write = write.iter().map(|e| e.to_string()).collect();
+
+        store
+            .create("admin", role, name, write, None)
+            .err()
+            .expect("the token is invalid")
+    }
+
+    #[test]
+    fn test_invalid_tokens_are_bad_requests() {
+        let store = test_store("invalid");
+
+        let err = create_error(&store, Role::Admin, "", &[]);
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+        let err = create_error(&store, Role::Admin, "ci", &["other"]);
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(err.to_string(), "Unknown entity type: other");
+
+        let err = create_error(&store, Role::Viewer, "ci", &["routes"]);
+        assert_eq!(err.status_code(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_scope_of_the_writes() {
+        let store = test_store("scope");
+        let (_, token) = create(&store, Role::Admin, &["routes", "upstreams"]);
+
+        assert!(token.can_write("/routes/1"));
+        assert!(token.can_write("/upstreams"));
+        assert!(!token.can_write("/services/1"));
+        assert!(!token.can_write("/routes/../services/1"));
+        assert!(!token.can_write("/routes%2F..%2Fservices/1"));
+
+        let (_, token) = create(&store, Role::Viewer, &[]);
+        assert!(!token.can_write("/routes/1"));
+    }
+}
@@ -1,36 +1,103 @@
 use actix_web::{
-    cookie::{time::Duration, Cookie},
-    post, web, HttpResponse,
+    cookie::{time::Duration, Cookie, SameSite},
+    get,
+    http::header,
+    post, web, HttpRequest, HttpResponse,
 };
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
 
 use crate::{
-    config::{HandlerConfig, User},
+    api_tokens::{ApiToken, ApiTokenStore},
+    config::{Cluster, HandlerConfig, Role, ServerConfig, CLUSTER_HEADER},
+    oidc::{self, OIDC_USER_PREFIX},
     server_error::{CommonResponse, RespError},
 };
 
+// Same as the max age of the cookie
+pub const SESSION_DURATION_SECS: u64 = 60 * 60 * 24 * 30;
+
 #[derive(Debug, Serialize, Deserialize)]
 struct TokenClaims {
-    exp: usize,
+    exp: u64,
+    // Required, so the tokens issued without an expiration are rejected
+    iat: u64,
+    // Display name of the IdP users, the `sub` is their id
+    #[serde(default)]
+    name: Option<String>,
+    role: Role,
     sub: String,
 }
 
-#[post("/login")]
-async fn login(config: HandlerConfig, body: web::Json<User>) -> CommonResponse {
-    if config.jwt_secret.is_empty() {
-        return Ok(HttpResponse::Ok().body("OK"));
-    }
+#[derive(Deserialize)]
+struct LoginBody {
+    username: String,
+    password: String,
+}
 
-    let found_user = config
-        .users
-        .iter()
-        .find(|u| u.username == body.username && u.password == body.password)
-        .ok_or(RespError::Unauthorized)?;
+pub struct AuthUser {
+    // Set when the request uses an API token instead of the cookie
+    pub api_token: Option<ApiToken>,
+    // Only used for display, like the `preferred_username` of the IdP users
+    pub display_name: String,
+    pub role: Role,
+    pub username: String,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct ApiTokenRequest {
+    name: String,
+    // Entity types that the token can modify, empty for a read-only token
+    write: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct ApiTokenResponse {
+    // Only returned here, the server only keeps its hash
+    secret: String,
+    token: ApiToken,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct RevokeApiTokenRequest {
+    id: String,
+}
+
+// Login methods available in the login page
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct AuthOptions {
+    has_oidc: bool,
+    has_password: bool,
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
 
+/// Cookie with the token checked by `verify_auth`, shared by the login methods
+pub fn token_cookie(
+    config: &ServerConfig,
+    username: &str,
+    display_name: Option<&str>,
+    role: Role,
+) -> Result<Cookie<'static>, RespError> {
+    let now = now_secs();
     let my_claims = TokenClaims {
-        exp: 9999999999,
-        sub: found_user.username.clone(),
+        exp: now + SESSION_DURATION_SECS,
+        iat: now,
+        name: display_name.map(str::to_string),
+        role,
+        sub: username.to_string(),
     };
 
     let token = encode(
@@ -40,43 +107,418 @@ async fn login(config: HandlerConfig, body: web::Json<User>) -> CommonResponse {
     )
     .map_err(|_| RespError::Unauthorized)?;
 
-    let c = Cookie::build("token", token)
+    Ok(Cookie::build("token", token)
         .path("/")
-        .max_age(Duration::new(60 * 60 * 24 * 30, 0))
-        .finish();
+        .max_age(Duration::new(SESSION_DURATION_SECS as i64, 0))
+        .secure(config.secure_cookies())
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .finish())
+}
+
+#[post("/login")]
+async fn login(config: HandlerConfig, body: web::Json<LoginBody>) -> CommonResponse {
+    if config.jwt_secret.is_empty() {
+        return Ok(HttpResponse::Ok().body("OK"));
+    }
+
+    let found_user = config
+        .users
+        .iter()
+        .find(|u| u.username == body.username && u.password == body.password)
+        .ok_or(RespError::Unauthorized)?;
+
+    let c = token_cookie(&config, &found_user.username, None, found_user.role)?;
 
     Ok(HttpResponse::Ok().cookie(c).body("OK"))
 }
 
 #[post("/logout")]
-async fn logout() -> CommonResponse {
+async fn logout(config: HandlerConfig) -> CommonResponse {
     let c = Cookie::build("token", "")
         .path("/")
-        .secure(false)
+        .secure(config.secure_cookies())
         .http_only(true)
+        .same_site(SameSite::Lax)
         .finish();
 
     Ok(HttpResponse::Ok().cookie(c).body("OK"))
 }
 
+#[get("/options")]
+async fn get_options(config: HandlerConfig) -> CommonResponse {
+    Ok(HttpResponse::Ok().json(AuthOptions {
+        has_oidc: config.oidc.is_some(),
+        has_password: !config.users.is_empty(),
+    }))
+}
+
+// The tokens are managed with the session of the user, not with other tokens
+fn verify_session<'a>(
+    req: &HttpRequest,
+    config: &'a HandlerConfig,
+) -> Result<(AuthUser, &'a ApiTokenStore), RespError> {
+    let store = config.api_tokens.as_ref().ok_or(RespError::Custom(
+        "The API tokens are not configured".to_string(),
+    ))?;
+    let user = verify_auth(req, config)?.ok_or(RespError::Unauthorized)?;
+
+    if user.api_token.is_some() {
+        return Err(RespError::Forbidden);
+    }
+
+    Ok((user, store))
+}
+
+#[get("/tokens")]
+async fn get_api_tokens(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
+    let (user, store) = verify_session(&req, &config)?;
+
+    Ok(HttpResponse::Ok().json(store.list(&user.username)?))
+}
+
+#[post("/tokens")]
+async fn post_api_token(
+    req: HttpRequest,
+    config: HandlerConfig,
+    body: web::Json<ApiTokenRequest>,
+) -> CommonResponse {
+    let (user, store) = verify_session(&req, &config)?;
+    let body = body.into_inner();
+    let expires_at = user
+        .username
+        .starts_with(OIDC_USER_PREFIX)
+        .then(|| now_secs() + SESSION_DURATION_SECS);
+    let (secret, token) = store.create(
+        &user.username,
+        user.role,
+        &body.name,
+        body.write,
+        expires_at,
+    )?;
+
+    Ok(HttpResponse::Ok().json(ApiTokenResponse { secret, token }))
+}
+
+#[post("/tokens/revoke")]
+async fn post_revoke_api_token(
+    req: HttpRequest,
+    config: HandlerConfig,
+    body: web::Json<RevokeApiTokenRequest>,
+) -> CommonResponse {
+    let (user, store) = verify_session(&req, &config)?;
+
+    store.revoke(&user.username, &body.id)?;
+
+    Ok(HttpResponse::Ok().body("OK"))
+}
+
 pub fn get_auth_scope() -> actix_web::Scope {
-    web::scope("/auth").service(login).service(logout)
+    web::scope("/auth")
+        .service(login)
+        .service(logout)
+        .service(get_options)
+        .service(get_api_tokens)
+        .service(post_api_token)
+        .service(post_revoke_api_token)
+        .service(oidc::oidc_login)
+        .service(oidc::oidc_callback)
+}
+
+pub fn bearer_token(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+}
+
+// The users of the config are checked in every request, so removing or demoting them is immediate
+fn config_user_role(config: &ServerConfig, username: &str) -> Result<Role, RespError> {
+    config
+        .users
+        .iter()
+        .find(|u| u.username == username)
+        .map(|u| u.role)
+        .ok_or(RespError::Unauthorized)
 }
 
-pub fn verify_auth(req: &actix_web::HttpRequest, config: &HandlerConfig) -> Result<(), RespError> {
+// The tokens can read like a viewer, and only write the entity types of their scope
+fn verify_api_token(config: &ServerConfig, secret: &str) -> Result<AuthUser, RespError> {
+    let mut token = config
+        .api_tokens
+        .as_ref()
+        .and_then(|store| store.find(secret))
+        .ok_or(RespError::Unauthorized)?;
+
+    if token.expires_at.is_some_and(|exp| exp <= now_secs()) {
+        return Err(RespError::Unauthorized);
+    }
+
+    // Removing or downgrading a user of the config also affects its tokens, the tokens of the
+    // IdP users are only valid until they expire
+    if token.username.starts_with(OIDC_USER_PREFIX) {
+        if config.oidc.is_none() || token.expires_at.is_none() {
+            return Err(RespError::Unauthorized);
+        }
+    } else {
+        token.role = token.role.min(config_user_role(config, &token.username)?);
+    }
+
+    Ok(AuthUser {
+        display_name: token.username.clone(),
+        role: Role::Viewer,
+        username: token.username.clone(),
+        api_token: Some(token),
+    })
+}
+
+// Returns the user of the cookie or the API token, or None when the auth is disabled
+pub fn verify_auth(
+    req: &HttpRequest,
+    config: &HandlerConfig,
+) -> Result<Option<AuthUser>, RespError> {
     if config.jwt_secret.is_empty() {
-        return Ok(());
+        return Ok(None);
+    }
+
+    if let Some(secret) = bearer_token(req) {
+        return verify_api_token(config, secret).map(Some);
     }
 
     let token = req.cookie("token").ok_or(RespError::Unauthorized)?;
     let token = token.value();
 
-    decode::<TokenClaims>(
+    let token_data = decode::<TokenClaims>(
         token,
         &DecodingKey::from_secret(config.jwt_secret.as_ref()),
         &Validation::default(),
     )
     .map_err(|_| RespError::Unauthorized)?;
+    let claims = token_data.claims;
+
+    // The IdP isn't asked again, so the role of its users lasts until the token expires
+    let role = if claims.sub.starts_with(OIDC_USER_PREFIX) {
+        if config.oidc.is_none() {
+            return Err(RespError::Unauthorized);
+        }
+
+        claims.role
+    } else {
+        config_user_role(config, &claims.sub)?
+    };
+
+    Ok(Some(AuthUser {
+        api_token: None,
+        display_name: claims.name.unwrap_or_else(|| claims.sub.clone()),
+        role,
+        username: claims.sub,
+    }))
+}
+
+// Without auth every request is allowed
+pub fn verify_role(user: Option<&AuthUser>, role: Role) -> Result<(), RespError> {
+    match user {
+        Some(user) if user.role < role => Err(RespError::Forbidden),
+        _ => Ok(()),
+    }
+}
+
+// The API tokens can only modify the entity types of their scope, the users need the admin role
+pub fn verify_admin_write(user: Option<&AuthUser>, uri: &str) -> Result<(), RespError> {
+    match user.and_then(|u| u.api_token.as_ref()) {
+        Some(token) if token.can_write(uri) => Ok(()),
+        Some(_) => Err(RespError::Forbidden),
+        None => verify_role(user, Role::Admin),
+    }
+}
+
+// Verifies the auth and that the user can access the cluster selected in the request
+pub fn verify_cluster<'a>(
+    req: &HttpRequest,
+    config: &'a HandlerConfig,
+) -> Result<&'a Cluster, RespError> {
+    let user = verify_auth(req, config)?;
+    let cluster_name = req
+        .headers()
+        .get(CLUSTER_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .filter(|h| !h.is_empty());
+
+    config.get_cluster(cluster_name, user.as_ref().map(|u| u.username.as_str()))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Arc;
+
+    use actix_web::{test::TestRequest, web};
+
+    use super::*;
+    use crate::{config::User, oidc::tests::test_oidc};
+
+    pub fn server_config(users: Vec<User>) -> ServerConfig {
+        ServerConfig {
+            address: "127.0.0.1".to_string(),
+            allowed_origins: vec![],
+            api_tokens: None,
+            clusters: vec![],
+            jwt_secret: "secret".to_string(),
+            oidc: None,
+            port: 9000,
+            registries: Default::default(),
+            tls: None,
+            users,
+            versions: Default::default(),
+        }
+    }
+
+    fn test_config(users: Vec<User>) -> HandlerConfig {
+        web::Data::new(Arc::new(server_config(users)))
+    }
+
+    // Config with an empty tokens file, with the users and the OIDC login
+    fn tokens_config(name: &str, users: Vec<User>) -> ServerConfig {
+        let path = std::env::temp_dir().join(format!(
+            "apisix-panel-tokens-{name}-{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        ServerConfig {
+            api_tokens: Some(ApiTokenStore::load(path.to_str().unwrap()).unwrap()),
+            oidc: Some(test_oidc()),
+            ..server_config(users)
+        }
+    }
+
+    fn create_token(config: &ServerConfig, username: &str, expires_at: Option<u64>) -> String {
+        let store = config.api_tokens.as_ref().unwrap();
+        let write = vec!["routes".to_string()];
 
-    Ok(())
+        store
+            .create(username, Role::Admin, "ci", write, expires_at)
+            .unwrap()
+            .0
+    }
+
+    fn user(username: &str, role: Role) -> User {
+        User {
+            username: username.to_string(),
+            password: "pw".to_string(),
+            role,
+        }
+    }
+
+    fn auth_with_cookie(config: &HandlerConfig, cookie: Cookie) -> Result<AuthUser, RespError> {
+        let req = TestRequest::default().cookie(cookie).to_http_request();
+
+        verify_auth(&req, config).map(|u| u.expect("the auth is enabled"))
+    }
+
+    #[test]
+    fn test_token_expires_with_the_cookie() {
+        let config = test_config(vec![user("admin", Role::Admin)]);
+        let cookie = token_cookie(&config, "admin", None, Role::Admin).unwrap();
+        let claims = decode::<TokenClaims>(
+            cookie.value(),
+            &DecodingKey::from_secret(b"secret"),
+            &Validation::default(),
+        )
+        .unwrap()
+        .claims;
+
+        assert_eq!(claims.exp - claims.iat, SESSION_DURATION_SECS);
+    }
+
+    #[test]
+    fn test_config_user_role_is_checked_again() {
+        let config = test_config(vec![user("admin", Role::Admin)]);
+        let cookie = token_cookie(&config, "admin", None, Role::Admin).unwrap();
+
+        let demoted = test_config(vec![user("admin", Role::Viewer)]);
+        assert_eq!(
+            auth_with_cookie(&demoted, cookie.clone()).unwrap().role,
+            Role::Viewer
+        );
+
+        let removed = test_config(vec![]);
+        assert!(auth_with_cookie(&removed, cookie).is_err());
+    }
+
+    #[test]
+    fn test_token_without_expiration_is_rejected() {
+        let config = test_config(vec![user("admin", Role::Admin)]);
+        let token = encode(
+            &Header::default(),
+            &serde_json::json!({ "exp": 9999999999u64, "role": "admin", "sub": "admin" }),
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+
+        assert!(auth_with_cookie(&config, Cookie::new("token", token)).is_err());
+    }
+
+    #[test]
+    fn test_oidc_user_is_not_a_config_user() {
+        // An IdP user can't get the session of a config user with the same name
+        let config = test_config(vec![user("admin", Role::Admin)]);
+        let cookie = token_cookie(&config, "oidc:123", Some("admin"), Role::Viewer).unwrap();
+
+        // Rejected without the OIDC login configured
+        assert!(auth_with_cookie(&config, cookie).is_err());
+    }
+
+    #[test]
+    fn test_api_token_of_removed_user() {
+        // The OIDC login doesn't skip the check of the config users
+        let config = tokens_config("removed", vec![user("admin", Role::Admin)]);
+        let secret = create_token(&config, "admin", None);
+        let config = ServerConfig {
+            users: vec![],
+            ..config
+        };
+
+        assert!(verify_api_token(&config, &secret).is_err());
+    }
+
+    #[test]
+    fn test_api_token_of_demoted_user() {
+        let config = tokens_config("demoted", vec![user("admin", Role::Admin)]);
+        let secret = create_token(&config, "admin", None);
+        let token = verify_api_token(&config, &secret).unwrap().api_token;
+        assert!(token.is_some_and(|t| t.can_write("/routes/1")));
+
+        let config = ServerConfig {
+            users: vec![user("admin", Role::Viewer)],
+            ..config
+        };
+        let token = verify_api_token(&config, &secret).unwrap().api_token;
+        assert!(token.is_some_and(|t| !t.can_write("/routes/1")));
+    }
+
+    #[test]
+    fn test_revoked_or_expired_api_token() {
+        let config = tokens_config("expired", vec![user("admin", Role::Admin)]);
+        let store = config.api_tokens.as_ref().unwrap();
+        let expired = create_token(&config, "admin", Some(now_secs() - 1));
+        let revoked = create_token(&config, "admin", None);
+        let id = store.find(&revoked).unwrap().id;
+
+        assert!(verify_api_token(&config, &revoked).is_ok());
+        store.revoke("admin", &id).unwrap();
+        assert!(verify_api_token(&config, &revoked).is_err());
+        assert!(verify_api_token(&config, &expired).is_err());
+    }
+
+    #[test]
+    fn test_api_token_of_oidc_user_expires() {
+        let config = tokens_config("oidc", vec![]);
+        let valid = create_token(&config, "oidc:123", Some(now_secs() + 60));
+        let expired = create_token(&config, "oidc:123", Some(now_secs() - 1));
+        let without_expiration = create_token(&config, "oidc:123", None);
+
+        assert!(verify_api_token(&config, &valid).is_ok());
+        assert!(verify_api_token(&config, &expired).is_err());
+        assert!(verify_api_token(&config, &without_expiration).is_err());
+    }
 }
@@ -1,61 +1,431 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use actix_web::web;
+use apisix_admin_panel_core::proxy::ProxyFetchOpts;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{
+    admin_api_handler::{AdminApiHandler, VersionCache},
+    admin_standalone_handler::AdminStandaloneHandler,
+    api_tokens::ApiTokenStore,
+    oidc::{OidcConfig, OIDC_USER_PREFIX},
+    registry_cache::RegistryCache,
+    server_error::RespError,
+    tls::{load_certified_key, TlsConfig},
+};
+
+// Header sent by the web client with the name of the selected cluster
+pub const CLUSTER_HEADER: &str = "X-APISIX-Cluster";
+
+// Ordered by permissions, a viewer can only read the clusters
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, TS,
+)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum Role {
+    Viewer,
+    #[default]
+    Admin,
+}
 
 #[derive(Deserialize, Serialize)]
 pub struct User {
     pub username: String,
     pub password: String,
+    #[serde(default)]
+    pub role: Role,
 }
 
-pub struct ServerConfig {
+// Key of the APISIX examples, anyone can use it against an instance that keeps it
+pub const DEFAULT_API_KEY: &str = "edd1c9f034335f136f87ad84b625c8f1";
+
+fn default_admin_url() -> String {
+    "http://localhost:9180".to_string()
+}
+
+fn default_config_file_path() -> String {
+    "/usr/local/apisix/conf/config.yaml".to_string()
+}
+
+fn default_control_url() -> String {
+    "http://localhost:9090".to_string()
+}
+
+// An APISIX deployment, either traditional (admin API) or standalone (config file)
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cluster {
+    pub name: String,
+    #[serde(default = "default_admin_url")]
     pub admin_url: String,
+    #[serde(default)]
     pub api_key: String,
+    #[serde(default)]
     pub apisix_url: String,
+    #[serde(default = "default_config_file_path")]
     pub config_file_path: String,
+    #[serde(default = "default_control_url")]
     pub control_url: String,
-    pub jwt_secret: String,
+    #[serde(default)]
     pub standalone_config_path: Option<String>,
+    // Usernames allowed to access the cluster, all of them when missing
+    #[serde(default)]
+    pub users: Option<Vec<String>>,
+}
+
+impl Cluster {
+    // Without auth there are no usernames, so every cluster is accessible
+    pub fn is_allowed(&self, username: Option<&str>) -> bool {
+        match (&self.users, username) {
+            (Some(users), Some(username)) => users.iter().any(|u| u == username),
+            _ => true,
+        }
+    }
+
+    // Sends the request to the admin API, or to the config file in the standalone mode
+    pub async fn handle_admin(&self, opts: ProxyFetchOpts) -> Result<String, &'static str> {
+        if let Some(standalone_config_path) = self.standalone_config_path.clone() {
+            let handler = AdminStandaloneHandler {
+                config_path: standalone_config_path,
+            };
+            handler.handle(opts).await
+        } else {
+            let handler = AdminApiHandler {
+                api_key: self.api_key.clone(),
+                url: self.admin_url.clone(),
+            };
+            handler.handle(opts).await
+        }
+    }
+
+    fn from_env() -> Self {
+        Self {
+            name: "default".to_string(),
+            admin_url: env_var("APISIX_ADMIN_URL").unwrap_or_else(default_admin_url),
+            api_key: env_var("APISIX_API_KEY").unwrap_or_else(|| DEFAULT_API_KEY.to_string()),
+            apisix_url: env_var("APISIX_URL").unwrap_or_default(),
+            config_file_path: env_var("APISIX_CONFIG_FILE")
+                .unwrap_or_else(default_config_file_path),
+            control_url: env_var("APISIX_CONTROL_URL").unwrap_or_else(default_control_url),
+            standalone_config_path: env_var("APISIX_STANDALONE_CONFIG"),
+            users: None,
+        }
+    }
+
+    // The IdP users (`oidc:<sub>`) are only known after they log in, so they can't be checked
+    fn validate(
+        &self,
+        users: &[User],
+        has_oidc: bool,
+        allow_default_api_key: bool,
+        errors: &mut Vec<String>,
+    ) {
+        let name = &self.name;
+
+        if name.is_empty() {
+            errors.push("A cluster has an empty name".to_string());
+        }
+
+        if let Some(path) = &self.standalone_config_path {
+            if !Path::new(path).is_file() {
+                errors.push(format!(
+                    "Cluster {name}: standalone config file {path} not found"
+                ));
+            }
+        } else {
+            for (key, url) in [
+                ("admin_url", &self.admin_url),
+                ("control_url", &self.control_url),
+            ] {
+                if reqwest::Url::parse(url).is_err() {
+                    errors.push(format!("Cluster {name}: {key} {url} is not a valid URL"));
+                }
+            }
+
+            if self.api_key.is_empty() {
+                errors.push(format!("Cluster {name}: api_key is required"));
+            } else if self.api_key == DEFAULT_API_KEY && !allow_default_api_key {
+                errors.push(format!(
+                    "Cluster {name}: api_key is the default key of APISIX, change it or set allow_default_api_key"
+                ));
+            }
+        }
+
+        for username in self.users.iter().flatten() {
+            if has_oidc && username.starts_with(OIDC_USER_PREFIX) {
+                continue;
+            }
+
+            if !users.iter().any(|u| &u.username == username) {
+                errors.push(format!("Cluster {name}: unknown user {username}"));
+            }
+        }
+    }
+}
+
+// Settings of the config file, the env vars take precedence over them
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    address: Option<String>,
+    allow_default_api_key: Option<bool>,
+    allowed_origins: Option<Vec<String>>,
+    api_tokens_path: Option<String>,
+    clusters: Option<Vec<Cluster>>,
+    jwt_secret: Option<String>,
+    oidc: Option<OidcConfig>,
+    port: Option<u16>,
+    tls: Option<TlsConfig>,
+    users: Option<Vec<User>>,
+}
+
+impl FileConfig {
+    // TOML files are detected by the extension, the rest are parsed as YAML (or JSON)
+    fn read(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read the config file {path}: {e}"))?;
+
+        if path.ends_with(".toml") {
+            toml::from_str(&content).map_err(|e| format!("Invalid config file {path}: {e}"))
+        } else {
+            serde_yaml::from_str(&content).map_err(|e| format!("Invalid config file {path}: {e}"))
+        }
+    }
+}
+
+// Empty env vars are considered missing
+fn env_var(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+// The file has a YAML (or JSON) list of clusters
+fn read_clusters_file(path: &str) -> Result<Vec<Cluster>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read the clusters file {path}: {e}"))?;
+
+    serde_yaml::from_str(&content).map_err(|e| format!("Invalid clusters file {path}: {e}"))
+}
+
+pub struct ServerConfig {
+    pub address: String,
+    // Origins of the web clients served from other ports, like the development server. They must
+    // have the scheme and host of the panel to read and send its cookies
+    pub allowed_origins: Vec<String>,
+    // When missing, the API tokens are disabled
+    pub api_tokens: Option<ApiTokenStore>,
+    // The first cluster is used when the request doesn't select one
+    pub clusters: Vec<Cluster>,
+    pub jwt_secret: String,
+    // Login with an OpenID Connect provider, besides the users of the config
+    pub oidc: Option<OidcConfig>,
+    pub port: u16,
+    // Plugins of the config files of the clusters
+    pub registries: RegistryCache,
+    // When missing, the server uses plain HTTP
+    pub tls: Option<TlsConfig>,
     pub users: Vec<User>,
+    // APISIX versions of the clusters, detected from the admin API
+    pub versions: VersionCache,
 }
 
 impl ServerConfig {
-    pub fn new() -> Self {
-        let api_key = std::env::var("APISIX_API_KEY")
-            .unwrap_or_else(|_| "edd1c9f034335f136f87ad84b625c8f1".to_string());
+    /// Reads the optional config file and the env vars, and returns every invalid setting
+    pub fn load(config_path: Option<&str>) -> Result<Self, Vec<String>> {
+        let file = match config_path {
+            Some(path) => FileConfig::read(path).map_err(|e| vec![e])?,
+            None => FileConfig::default(),
+        };
+        let mut errors = vec![];
+
+        let address = env_var("ADDRESS")
+            .or(file.address)
+            .unwrap_or_else(|| "0.0.0.0".to_string());
+
+        let port = match env_var("PORT") {
+            Some(port) => port.parse().unwrap_or_else(|_| {
+                errors.push(format!("PORT {port} is not a valid port"));
+                0
+            }),
+            None => file.port.unwrap_or(9000),
+        };
 
-        let admin_url = std::env::var("APISIX_ADMIN_URL")
-            .unwrap_or_else(|_| "http://localhost:9180".to_string());
+        let allow_default_api_key = match env_var("APISIX_ALLOW_DEFAULT_API_KEY") {
+            Some(allow) => allow == "true",
+            None => file.allow_default_api_key.unwrap_or(false),
+        };
 
-        let apisix_url = std::env::var("APISIX_URL").unwrap_or_else(|_| "".to_string());
+        let allowed_origins = match env_var("APISIX_ALLOWED_ORIGINS") {
+            Some(origins) => origins.split(',').map(|o| o.trim().to_string()).collect(),
+            None => file.allowed_origins.unwrap_or_default(),
+        };
 
-        let config_file_path = std::env::var("APISIX_CONFIG_FILE")
-            .unwrap_or_else(|_| "/usr/local/apisix/conf/config.yaml".to_string());
+        let api_tokens = env_var("APISIX_API_TOKENS_FILE")
+            .or(file.api_tokens_path)
+            .and_then(|path| ApiTokenStore::load(&path).map_err(|e| errors.push(e)).ok());
 
-        let control_url = std::env::var("APISIX_CONTROL_URL")
-            .unwrap_or_else(|_| "http://localhost:9090".to_string());
+        let clusters = match env_var("APISIX_CLUSTERS_FILE") {
+            Some(path) => read_clusters_file(&path).unwrap_or_else(|e| {
+                errors.push(e);
+                vec![]
+            }),
+            None => file.clusters.unwrap_or_else(|| vec![Cluster::from_env()]),
+        };
 
-        let standalone_config_path = match std::env::var("APISIX_STANDALONE_CONFIG") {
-            Ok(path) => Some(path),
-            Err(_) => None,
+        let users = match env_var("APISIX_USERS") {
+            Some(users) => serde_json::from_str(&users).unwrap_or_else(|e| {
+                errors.push(format!(
+                    "APISIX_USERS is not a valid JSON list of users: {e}"
+                ));
+                vec![]
+            }),
+            None => file.users.unwrap_or_default(),
         };
 
-        let users_str = std::env::var("APISIX_USERS").unwrap_or_else(|_| "[]".to_string());
-        let users =
-            serde_json::from_str::<Vec<User>>(&users_str).expect("Failed to parse users JSON");
-        let jwt_secret = std::env::var("APISIX_JWT_SECRET").unwrap_or_else(|_| "".to_string());
+        let jwt_secret = env_var("APISIX_JWT_SECRET")
+            .or(file.jwt_secret)
+            .unwrap_or_default();
 
-        Self {
-            admin_url,
-            api_key,
-            apisix_url,
-            config_file_path,
-            control_url,
+        let tls = match (env_var("APISIX_TLS_CERT"), env_var("APISIX_TLS_KEY")) {
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path,
+                key_path,
+            }),
+            (None, None) => file.tls,
+            _ => {
+                errors.push("APISIX_TLS_CERT and APISIX_TLS_KEY must be set together".to_string());
+                None
+            }
+        };
+
+        let config = Self {
+            address,
+            allowed_origins,
+            api_tokens,
+            clusters,
             jwt_secret,
-            standalone_config_path,
+            oidc: file.oidc,
+            port,
+            registries: RegistryCache::default(),
+            tls,
             users,
+            versions: VersionCache::default(),
+        };
+
+        config.validate(allow_default_api_key, &mut errors);
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate(&self, allow_default_api_key: bool, errors: &mut Vec<String>) {
+        if self.clusters.is_empty() {
+            errors.push("At least one cluster is required".to_string());
         }
+
+        for (idx, cluster) in self.clusters.iter().enumerate() {
+            if self.clusters[..idx].iter().any(|c| c.name == cluster.name) {
+                errors.push(format!("Duplicated cluster name: {}", cluster.name));
+            }
+
+            cluster.validate(
+                &self.users,
+                self.oidc.is_some(),
+                allow_default_api_key,
+                errors,
+            );
+        }
+
+        for (idx, user) in self.users.iter().enumerate() {
+            if user.username.is_empty() || user.password.is_empty() {
+                errors.push(format!("User {idx} has an empty username or password"));
+            }
+
+            if user.username.starts_with(OIDC_USER_PREFIX) {
+                errors.push(format!(
+                    "User {}: the {OIDC_USER_PREFIX} prefix is reserved for the OIDC users",
+                    user.username
+                ));
+            }
+
+            if self.users[..idx]
+                .iter()
+                .any(|u| u.username == user.username)
+            {
+                errors.push(format!("Duplicated user: {}", user.username));
+            }
+        }
+
+        // The origins are compared with the `Origin` header, which has no path
+        for origin in &self.allowed_origins {
+            let is_valid = reqwest::Url::parse(origin)
+                .is_ok_and(|url| url.origin().ascii_serialization() == *origin);
+
+            if !is_valid {
+                errors.push(format!(
+                    "Allowed origin {origin} must be like https://example.com:8080"
+                ));
+            }
+        }
+
+        if let Some(tls) = &self.tls {
+            if let Err(e) = load_certified_key(tls) {
+                errors.push(e);
+            }
+        }
+
+        // Without the secret the auth is disabled, so the users would be ignored
+        if !self.users.is_empty() && self.jwt_secret.is_empty() {
+            errors.push("The users require a jwt_secret to enable the auth".to_string());
+        }
+
+        if self.api_tokens.is_some() && self.jwt_secret.is_empty() {
+            errors.push("The API tokens require a jwt_secret to enable the auth".to_string());
+        }
+
+        if let Some(oidc) = &self.oidc {
+            oidc.validate(errors);
+
+            // The panel issues its own token after the IdP login
+            if self.jwt_secret.is_empty() {
+                errors.push("The OIDC login requires a jwt_secret".to_string());
+            }
+        }
+    }
+
+    // The cookies can only be sent over HTTPS when the server terminates TLS itself
+    pub fn secure_cookies(&self) -> bool {
+        self.tls.is_some()
+    }
+
+    pub fn user_clusters(&self, username: Option<&str>) -> Vec<&Cluster> {
+        self.clusters
+            .iter()
+            .filter(|c| c.is_allowed(username))
+            .collect()
+    }
+
+    // Unknown clusters are also forbidden, to not disclose the names of the other clusters
+    pub fn get_cluster(
+        &self,
+        name: Option<&str>,
+        username: Option<&str>,
+    ) -> Result<&Cluster, RespError> {
+        let cluster = match name {
+            Some(name) => self.clusters.iter().find(|c| c.name == name),
+            None => self.user_clusters(username).into_iter().next(),
+        }
+        .ok_or(RespError::Forbidden)?;
+
+        if !cluster.is_allowed(username) {
+            return Err(RespError::Forbidden);
+        }
+
+        Ok(cluster)
     }
 }
 
@@ -0,0 +1,235 @@
+use actix_cors::Cors;
+use actix_web::{
+    body::MessageBody,
+    cookie::{Cookie, SameSite},
+    dev::{ServiceRequest, ServiceResponse},
+    http::{header, Method},
+    middleware::Next,
+    Error, HttpRequest,
+};
+use rand::{distributions::Alphanumeric, Rng};
+
+use crate::{
+    auth::bearer_token,
+    config::{HandlerConfig, ServerConfig, CLUSTER_HEADER},
+    server_error::RespError,
+};
+
+pub const CSRF_COOKIE: &str = "csrf_token";
+pub const CSRF_HEADER: &str = "X-CSRF-Token";
+
+// Only the listed origins can call the API from the browser, the rest must be same-origin
+pub fn get_cors(config: &ServerConfig) -> Cors {
+    let allowed_origins = config.allowed_origins.clone();
+    let is_tls = config.secure_cookies();
+
+    Cors::default()
+        .allowed_origin_fn(move |origin, req_head| {
+            let origin = origin.to_str().unwrap_or_default();
+            let header = |name: &str| {
+                req_head
+                    .headers()
+                    .get(name)
+                    .and_then(|h| h.to_str().ok())
+                    .unwrap_or_default()
+            };
+            // Behind a proxy that terminates TLS, the scheme comes in the header
+            let scheme = match header("X-Forwarded-Proto") {
+                "" if is_tls => "https",
+                "" => "http",
+                scheme => scheme,
+            };
+
+            is_same_origin(scheme, header(header::HOST.as_str()), origin)
+                || allowed_origins.iter().any(|o| o == origin)
+        })
+        .allowed_methods(["GET", "POST"])
+        .allowed_headers([header::CONTENT_TYPE.as_str(), CLUSTER_HEADER, CSRF_HEADER])
+        .supports_credentials()
+}
+
+pub fn random_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+fn header_value<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    req.headers().get(name).and_then(|h| h.to_str().ok())
+}
+
+// An HTTP page can't call the API served with HTTPS, the scheme is part of the origin
+fn is_same_origin(scheme: &str, host: &str, origin: &str) -> bool {
+    origin
+        .split_once("://")
+        .is_some_and(|(origin_scheme, origin_host)| {
+            origin_scheme.eq_ignore_ascii_case(scheme) && origin_host == host
+        })
+}
+
+// Browsers send the origin in the cross-site requests, which must be one of the allowed ones
+fn verify_origin(req: &HttpRequest, config: &ServerConfig) -> Result<(), RespError> {
+    let connection_info = req.connection_info().clone();
+    let is_allowed = |origin: &str| {
+        is_same_origin(connection_info.scheme(), connection_info.host(), origin)
+            || config.allowed_origins.iter().any(|o| o == origin)
+    };
+
+    if let Some(origin) = header_value(req, header::ORIGIN.as_str()) {
+        if !is_allowed(origin) {
+            return Err(RespError::Forbidden);
+        }
+    } else if header_value(req, "Sec-Fetch-Site") == Some("cross-site") {
+        return Err(RespError::Forbidden);
+    }
+
+    Ok(())
+}
+
+// Double-submit check: other sites can't read the cookie to copy it in the header
+fn verify_token(req: &HttpRequest) -> Result<(), RespError> {
+    let cookie = req.cookie(CSRF_COOKIE).ok_or(RespError::Forbidden)?;
+    let token = header_value(req, CSRF_HEADER).ok_or(RespError::Forbidden)?;
+
+    if cookie.value().is_empty() || cookie.value() != token {
+        return Err(RespError::Forbidden);
+    }
+
+    Ok(())
+}
+
+// Routes that change data with the session cookie, and the login and logout to prevent logging
+// in the user with another account
+fn is_protected(req: &ServiceRequest) -> bool {
+    let path = req.path();
+
+    req.method() == Method::POST && (path.starts_with("/api/") || path.starts_with("/auth/"))
+}
+
+/// Protects the `POST` routes of the session, and sets the token cookie when it is missing
+pub async fn csrf_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    // The browsers don't send the API tokens on their own, unlike the cookies
+    if is_protected(&req) && bearer_token(req.request()).is_none() {
+        let config = req
+            .app_data::<HandlerConfig>()
+            .ok_or(RespError::Custom("Missing config".to_string()))?;
+
+        verify_origin(req.request(), config)?;
+        verify_token(req.request())?;
+    }
+
+    let secure = req
+        .app_data::<HandlerConfig>()
+        .is_some_and(|config| config.secure_cookies());
+    let needs_token = req.cookie(CSRF_COOKIE).is_none();
+
+    let mut res = next.call(req).await?;
+
+    if needs_token {
+        // Readable by the web client, which sends it back in the header. Only on the host of the
+        // panel, so the allowed origins can only differ in the port
+        let cookie = Cookie::build(CSRF_COOKIE, random_token())
+            .path("/")
+            .secure(secure)
+            .http_only(false)
+            .same_site(SameSite::Strict)
+            .finish();
+
+        res.response_mut().add_cookie(&cookie)?;
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{cookie::Cookie, test::TestRequest};
+
+    use super::*;
+    use crate::auth::tests::server_config;
+
+    #[test]
+    fn test_same_origin() {
+        assert!(is_same_origin("https", "panel:9000", "https://panel:9000"));
+        assert!(!is_same_origin("https", "panel:9000", "http://panel:9000"));
+        assert!(!is_same_origin("http", "panel:9000", "http://panel"));
+        assert!(!is_same_origin("http", "panel", "panel"));
+    }
+
+    #[test]
+    fn test_verify_origin() {
+        let config = ServerConfig {
+            allowed_origins: vec!["http://localhost:3000".to_string()],
+            ..server_config(vec![])
+        };
+        let request = |headers: &[(&str, &str)]| {
+            let mut req = TestRequest::post().insert_header(("Host", "panel"));
+
+            for header in headers {
+                req = req.insert_header(*header);
+            }
+
+            verify_origin(&req.to_http_request(), &config)
+        };
+
+        assert!(request(&[("Origin", "http://panel")]).is_ok());
+        assert!(request(&[("Origin", "http://localhost:3000")]).is_ok());
+        assert!(request(&[("Origin", "http://evil")]).is_err());
+        assert!(request(&[("Origin", "http://panel"), ("X-Forwarded-Proto", "https")]).is_err());
+        assert!(request(&[("Origin", "https://panel"), ("X-Forwarded-Proto", "https")]).is_ok());
+
+        // Without the origin, only the cross-site requests are rejected
+        assert!(request(&[]).is_ok());
+        assert!(request(&[("Sec-Fetch-Site", "same-origin")]).is_ok());
+        assert!(request(&[("Sec-Fetch-Site", "cross-site")]).is_err());
+    }
+
+    #[test]
+    fn test_verify_token() {
+        let request = |cookie: Option<&str>, header: Option<&str>| {
+            let mut req = TestRequest::post();
+
+            if let Some(cookie) = cookie {
+                req = req.cookie(Cookie::new(CSRF_COOKIE, cookie.to_string()));
+            }
+            if let Some(header) = header {
+                req = req.insert_header((CSRF_HEADER, header));
+            }
+
+            verify_token(&req.to_http_request())
+        };
+
+        assert!(request(Some("abc"), Some("abc")).is_ok());
+        assert!(request(Some("abc"), Some("abd")).is_err());
+        assert!(request(Some("abc"), None).is_err());
+        assert!(request(None, Some("abc")).is_err());
+        assert!(request(Some(""), Some("")).is_err());
+    }
+
+    #[test]
+    fn test_is_protected() {
+        for uri in [
+            "/api/apisix-admin",
+            "/auth/login",
+            "/auth/logout",
+            "/auth/tokens",
+        ] {
+            assert!(
+                is_protected(&TestRequest::post().uri(uri).to_srv_request()),
+                "{uri}"
+            );
+        }
+
+        assert!(!is_protected(
+            &TestRequest::get().uri("/auth/options").to_srv_request()
+        ));
+        assert!(!is_protected(
+            &TestRequest::post().uri("/").to_srv_request()
+        ));
+    }
+}
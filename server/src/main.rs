@@ -1,26 +1,104 @@
-use actix_cors::Cors;
 use actix_files::{Files, NamedFile};
 use actix_web::{
     dev::{fn_service, ServiceRequest, ServiceResponse},
     get,
     http::StatusCode,
+    middleware::{from_fn, Condition, DefaultHeaders},
     post, web, App, HttpRequest, HttpResponse, HttpServer, Responder,
 };
 use admin_api_handler::AdminApiHandler;
-use admin_standalone_handler::AdminStandaloneHandler;
-use apisix_admin_panel_core::proxy::{ProxyFetchMethod, ProxyFetchOpts};
-use auth::{get_auth_scope, verify_auth};
-use config::{HandlerConfig, ServerConfig};
+use apisix_admin_panel_core::{
+    apisix::{
+        admin_uri::validate_admin_uri,
+        common::prelude::*,
+        consumers::Consumer,
+        control_plane::ControlPlane,
+        plugins::{registry::PluginRegistry, wasm_plugins::wasm_plugin_definitions},
+        redaction::{
+            config_sensitive_paths, has_redacted, redact_entity, redact_response, restore_redacted,
+            sensitive_paths,
+        },
+        schema::ApisixSchema,
+    },
+    proxy::{ProxyFetchMethod, ProxyFetchOpts},
+};
+use auth::{get_auth_scope, verify_admin_write, verify_auth, verify_cluster, verify_role};
+use config::{Cluster, HandlerConfig, Role, ServerConfig};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use server_error::{CommonResponse, RespError};
-use std::{io::Read, sync::Arc};
+use std::{io::Read, sync::Arc, time::Duration};
 use ts_rs::TS;
 
 mod admin_api_handler;
 mod admin_standalone_handler;
+mod api_tokens;
 mod auth;
 mod config;
+mod csrf;
+mod oidc;
+mod promotion;
+mod registry_cache;
 mod server_error;
+mod tls;
+
+// The stored entity, the standalone mode returns the whole list for the uri of an item
+async fn fetch_stored_entity(cluster: &Cluster, uri: &str) -> Option<Value> {
+    let text = cluster
+        .handle_admin(ProxyFetchOpts::get(uri).ok()?)
+        .await
+        .ok()?;
+    let response: Value = serde_json::from_str(&text).ok()?;
+
+    match response.get("list") {
+        Some(Value::Array(list)) => {
+            let id = uri.rsplit('/').next()?;
+
+            list.iter()
+                .filter_map(|item| item.get("value"))
+                .find(|value| {
+                    let item_id = value.get("id").or_else(|| value.get("username"));
+                    item_id.and_then(Value::as_str) == Some(id)
+                })
+                .cloned()
+        }
+        _ => response.get("value").cloned(),
+    }
+}
+
+// The client sends back the masked values that weren't changed, which keep the stored ones
+async fn restore_stored_values(
+    cluster: &Cluster,
+    mut opts: ProxyFetchOpts,
+    paths: &[Vec<String>],
+) -> Result<ProxyFetchOpts, RespError> {
+    let Some(mut value) = opts
+        .data
+        .as_deref()
+        .and_then(|data| serde_json::from_str::<Value>(data).ok())
+    else {
+        return Ok(opts);
+    };
+
+    if !has_redacted(&value, paths) {
+        return Ok(opts);
+    }
+
+    // Consumers are written to the prefix, with the username in the body
+    let uri = match value.get("username").and_then(Value::as_str) {
+        Some(username) if opts.uri == Consumer::API_PREFIX => format!("{}/{}", opts.uri, username),
+        _ => opts.uri.clone(),
+    };
+    let stored = match opts.method {
+        ProxyFetchMethod::PATCH | ProxyFetchMethod::PUT => fetch_stored_entity(cluster, &uri).await,
+        _ => None,
+    };
+
+    restore_redacted(&mut value, stored.as_ref(), paths).map_err(RespError::BadRequest)?;
+    opts.data = Some(value.to_string());
+
+    Ok(opts)
+}
 
 #[post("/api/apisix-admin")]
 async fn post_proxy_apisix_admin(
@@ -28,59 +106,125 @@ async fn post_proxy_apisix_admin(
     config: HandlerConfig,
     body: web::Json<ProxyFetchOpts>,
 ) -> CommonResponse {
-    verify_auth(&req, &config)?;
+    let cluster = verify_cluster(&req, &config)?;
 
-    let text = (if let Some(standalone_config_path) = config.standalone_config_path.clone() {
-        let handler = AdminStandaloneHandler {
-            config_path: standalone_config_path,
-        };
-        handler.handle(body.into_inner()).await
-    } else {
-        let handler = AdminApiHandler {
-            api_key: config.api_key.clone(),
-            url: config.admin_url.clone(),
-        };
-        handler.handle(body.into_inner()).await
-    })
-    .map_err(|_| RespError::Custom("Error fetching data from APISIX admin API".to_string()))?;
+    validate_admin_uri(&body.uri).map_err(RespError::Denied)?;
+
+    // The viewers can only read the entities
+    if body.method != ProxyFetchMethod::GET {
+        verify_admin_write(verify_auth(&req, &config)?.as_ref(), &body.uri)?;
+    }
+
+    let paths = sensitive_paths(&body.uri, &plugin_registry(&config, cluster));
+    let opts = restore_stored_values(cluster, body.into_inner(), &paths).await?;
+
+    let text = cluster
+        .handle_admin(opts)
+        .await
+        .map_err(|_| RespError::Custom("Error fetching data from APISIX admin API".to_string()))?;
+
+    // Errors and other responses that aren't JSON are returned as they are
+    let text = match serde_json::from_str::<Value>(&text) {
+        Ok(mut response) => {
+            redact_response(&mut response, &paths);
+            response.to_string()
+        }
+        Err(_) => text,
+    };
 
     return Ok(HttpResponse::Ok()
         .append_header(("Content-Type", "application/json"))
         .body(text));
 }
 
+const CONTROL_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+const CONTROL_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A control plane that doesn't answer fails with a 504, and an unreachable one with a 502
+fn control_error(e: reqwest::Error) -> RespError {
+    let status = match e.is_timeout() {
+        true => StatusCode::GATEWAY_TIMEOUT,
+        false => StatusCode::BAD_GATEWAY,
+    };
+
+    RespError::Upstream(
+        status.as_u16(),
+        "Error fetching data from APISIX control API".to_string(),
+    )
+}
+
+fn control_client() -> Result<reqwest::Client, RespError> {
+    reqwest::Client::builder()
+        .connect_timeout(CONTROL_CONNECT_TIMEOUT)
+        .timeout(CONTROL_TIMEOUT)
+        .build()
+        .map_err(|e| RespError::Custom(e.to_string()))
+}
+
+async fn fetch_control(request: reqwest::RequestBuilder) -> Result<String, RespError> {
+    let res = request.send().await.map_err(control_error)?;
+    let status = res.status();
+    let text = res.text().await.map_err(control_error)?;
+
+    // The errors of APISIX, like an unknown id, keep their status
+    if !status.is_success() {
+        return Err(RespError::Upstream(status.as_u16(), text));
+    }
+
+    Ok(text)
+}
+
 #[post("/api/apisix-control")]
 async fn post_proxy_apisix_control(
     req: HttpRequest,
     config: HandlerConfig,
     body: web::Json<ProxyFetchOpts>,
 ) -> CommonResponse {
-    verify_auth(&req, &config)?;
+    let cluster = verify_cluster(&req, &config)?;
 
-    let client = reqwest::Client::new();
+    if body.method != ProxyFetchMethod::GET {
+        verify_role(verify_auth(&req, &config)?.as_ref(), Role::Admin)?;
+    }
 
-    let url = format!("{}{}", config.control_url.clone(), body.uri);
+    ControlPlane::validate_opts(&body).map_err(RespError::Denied)?;
 
-    match body.method {
-        ProxyFetchMethod::GET => {
-            let res = client.get(&url).send().await.unwrap();
+    let url = format!("{}{}", cluster.control_url, body.uri);
+    let method = match body.method {
+        ProxyFetchMethod::DELETE => reqwest::Method::DELETE,
+        ProxyFetchMethod::GET => reqwest::Method::GET,
+        ProxyFetchMethod::PATCH => reqwest::Method::PATCH,
+        ProxyFetchMethod::POST => reqwest::Method::POST,
+        ProxyFetchMethod::PUT => reqwest::Method::PUT,
+    };
 
-            return Ok(HttpResponse::Ok()
-                .append_header(("Content-Type", "application/json"))
-                .body(res.text().await.unwrap()));
-        }
-        ProxyFetchMethod::PUT => {
-            let mut client = client.put(&url);
+    let mut request = control_client()?.request(method, &url);
 
-            if let Some(data) = &body.data {
-                client = client.body(data.clone());
-            }
+    if let Some(data) = &body.data {
+        request = request.body(data.clone());
+    }
+
+    let text = fetch_control(request).await?;
+
+    match serde_json::from_str::<Value>(&text) {
+        Ok(response) => {
+            let mut response = ControlPlane::parse_response(&body.uri, response).map_err(|e| {
+                RespError::Upstream(
+                    StatusCode::BAD_GATEWAY.as_u16(),
+                    format!("Invalid response of the APISIX control API: {e}"),
+                )
+            })?;
 
-            let res = client.send().await.unwrap();
+            if let Some(prefix) = ControlPlane::entity_api_prefix(&body.uri) {
+                redact_response(
+                    &mut response,
+                    &sensitive_paths(prefix, &plugin_registry(&config, cluster)),
+                );
+            }
 
-            Ok(HttpResponse::Ok().json(res.text().await.unwrap()))
+            Ok(HttpResponse::Ok().json(response))
         }
-        _ => Ok(HttpResponse::BadRequest().body("Method not allowed")),
+        // Some endpoints reply with plain text, like `done` after reloading the plugins
+        Err(_) => Ok(HttpResponse::Ok().json(text)),
     }
 }
 
@@ -89,13 +233,8 @@ async fn get_health() -> impl Responder {
     HttpResponse::Ok().body("OK")
 }
 
-#[get("/api/apisix-config")]
-async fn get_apisix_config(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
-    verify_auth(&req, &config)?;
-
-    let apisix_key = config.api_key.clone();
-
-    let mut file = NamedFile::open(config.config_file_path.clone())
+fn read_apisix_config(cluster: &Cluster) -> Result<serde_yaml::Value, RespError> {
+    let mut file = NamedFile::open(cluster.config_file_path.clone())
         .map_err(|_| RespError::Custom("APISIX config file not found".to_string()))?;
 
     let mut content = String::new();
@@ -103,33 +242,136 @@ async fn get_apisix_config(req: HttpRequest, config: HandlerConfig) -> CommonRes
     file.read_to_string(&mut content)
         .map_err(|_| RespError::Custom("Failed to read APISIX config file".to_string()))?;
 
-    let sanitized_content = content.replace(&apisix_key, "REDACTED");
-    let parsed_content: serde_yaml::Value = serde_yaml::from_str(&sanitized_content)
-        .map_err(|_| RespError::Custom("Failed to parse APISIX config file".to_string()))?;
-    let json_content = serde_json::to_string(&parsed_content)
+    let sanitized_content = content.replace(&cluster.api_key, "REDACTED");
+
+    serde_yaml::from_str(&sanitized_content)
+        .map_err(|_| RespError::Custom("Failed to parse APISIX config file".to_string()))
+}
+
+#[get("/api/apisix-config")]
+async fn get_apisix_config(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
+    let cluster = verify_cluster(&req, &config)?;
+
+    let parsed_content = read_apisix_config(cluster)?;
+    let mut json_content = serde_json::to_value(&parsed_content)
         .map_err(|_| RespError::Custom("Failed to convert APISIX config to JSON".to_string()))?;
 
+    redact_entity(&mut json_content, &config_sensitive_paths());
+    let json_content = json_content.to_string();
+
     Ok(HttpResponse::Ok()
         .append_header(("Content-Type", "application/yaml"))
         .body(json_content))
 }
 
+// The built-in plugins and the proxy-wasm plugins of the config file of the cluster, which is
+// only read again when it is modified
+pub fn plugin_registry(config: &ServerConfig, cluster: &Cluster) -> PluginRegistry {
+    config.registries.get(cluster, || {
+        let mut registry = PluginRegistry::new();
+
+        let file_config = read_apisix_config(cluster)
+            .ok()
+            .and_then(|c| serde_json::to_value(c).ok());
+
+        if let Some(file_config) = file_config {
+            registry.extend(wasm_plugin_definitions(&file_config));
+        }
+
+        registry
+    })
+}
+
+// The standalone mode doesn't use the admin API, so the version can't be detected
+async fn cluster_version(config: &ServerConfig, cluster: &Cluster) -> Option<String> {
+    match cluster.standalone_config_path {
+        Some(_) => None,
+        None => {
+            let handler = AdminApiHandler {
+                api_key: cluster.api_key.clone(),
+                url: cluster.admin_url.clone(),
+            };
+
+            config.versions.get(&handler).await
+        }
+    }
+}
+
+// Same definitions as the web client: filtered by the version, and generated from the schema
+// of the Control API when it is reachable
+#[get("/api/plugins")]
+async fn get_plugins(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
+    let cluster = verify_cluster(&req, &config)?;
+
+    let version = cluster_version(&config, cluster).await;
+
+    let schema = match control_client() {
+        Ok(client) => {
+            let request = client.get(format!("{}/v1/schema", cluster.control_url));
+
+            fetch_control(request)
+                .await
+                .ok()
+                .and_then(|text| serde_json::from_str::<ApisixSchema>(&text).ok())
+        }
+        Err(_) => None,
+    };
+
+    let definitions =
+        plugin_registry(&config, cluster).client_definitions(schema.as_ref(), version.as_deref());
+
+    Ok(HttpResponse::Ok().json(definitions))
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct ClusterInfo {
+    is_standalone: bool,
+    name: String,
+}
+
 #[derive(Serialize, Deserialize, TS)]
 #[ts(export)]
 struct ServerInfo {
     apisix_url: String,
+    apisix_version: Option<String>,
+    // Selected cluster, the rest of the fields describe it
+    cluster: String,
+    // Clusters that the user can access
+    clusters: Vec<ClusterInfo>,
     is_standalone: bool,
     has_auth: bool,
+    // Role of the logged in user, None when the auth is disabled
+    role: Option<Role>,
+    // Name to display for the logged in user
+    username: Option<String>,
 }
 
 #[get("/api/info")]
 async fn get_info(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
-    verify_auth(&req, &config)?;
+    let cluster = verify_cluster(&req, &config)?;
+    let user = verify_auth(&req, &config)?;
+
+    let apisix_version = cluster_version(&config, cluster).await;
+
+    let clusters = config
+        .user_clusters(user.as_ref().map(|u| u.username.as_str()))
+        .into_iter()
+        .map(|c| ClusterInfo {
+            is_standalone: c.standalone_config_path.is_some(),
+            name: c.name.clone(),
+        })
+        .collect();
 
     let server_info = ServerInfo {
-        apisix_url: config.apisix_url.clone(),
+        apisix_url: cluster.apisix_url.clone(),
+        apisix_version,
+        cluster: cluster.name.clone(),
+        clusters,
         has_auth: !config.jwt_secret.is_empty(),
-        is_standalone: config.standalone_config_path.is_some(),
+        is_standalone: cluster.standalone_config_path.is_some(),
+        role: user.as_ref().map(|u| u.role),
+        username: user.map(|u| u.display_name),
     };
     let json_str = serde_json::to_string(&server_info).unwrap();
 
@@ -138,19 +380,72 @@ async fn get_info(req: HttpRequest, config: HandlerConfig) -> CommonResponse {
         .body(json_str))
 }
 
+const USAGE: &str = "Usage: apisix-admin-panel [--config <path>] [--check-config]";
+
+struct Args {
+    check_config: bool,
+    config_path: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        check_config: false,
+        config_path: std::env::var("APISIX_PANEL_CONFIG").ok(),
+    };
+    let mut iter = std::env::args().skip(1);
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--check-config" => args.check_config = true,
+            "--config" => {
+                args.config_path = Some(iter.next().ok_or("Missing the path of --config")?);
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+
+    Ok(args)
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    let default_port = 9000;
-    let port = std::env::var("PORT")
-        .unwrap_or_else(|_| default_port.to_string())
-        .parse()
-        .unwrap_or(9000);
-    let address = std::env::var("ADDRESS").unwrap_or_else(|_| "0.0.0.0".to_string());
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("{e}\n{USAGE}");
+        std::process::exit(2);
+    });
 
-    println!("Starting the APISIX HTTP server on http://{address}:{port}");
+    let server_config = ServerConfig::load(args.config_path.as_deref()).unwrap_or_else(|errors| {
+        eprintln!("Invalid configuration:");
+        for error in errors {
+            eprintln!("- {error}");
+        }
+        std::process::exit(1);
+    });
 
-    HttpServer::new(move || {
-        let cors = Cors::permissive();
+    if args.check_config {
+        println!("The configuration is valid");
+        return Ok(());
+    }
+
+    let address = server_config.address.clone();
+    let port = server_config.port;
+    let rustls_config = server_config
+        .tls
+        .as_ref()
+        .map(tls::rustls_config)
+        .transpose();
+    let rustls_config = rustls_config.unwrap_or_else(|e| {
+        eprintln!("Failed to configure TLS: {e}");
+        std::process::exit(1);
+    });
+    let is_tls = rustls_config.is_some();
+    let config = web::Data::new(Arc::new(server_config));
+
+    let scheme = if is_tls { "https" } else { "http" };
+    println!("Starting the APISIX HTTP server on {scheme}://{address}:{port}");
+
+    let server = HttpServer::new(move || {
+        let cors = csrf::get_cors(&config);
         let static_files = Files::new("/", "./static")
             .index_file("index.html")
             .redirect_to_slash_directory()
@@ -167,21 +462,97 @@ async fn main() -> std::io::Result<()> {
                 Ok(ServiceResponse::new(req, res))
             }));
 
-        let config = web::Data::new(Arc::new(ServerConfig::new()));
         let auth_scope = get_auth_scope();
 
         App::new()
-            .app_data(config)
+            .app_data(config.clone())
             .service(auth_scope)
             .service(get_apisix_config)
             .service(get_health)
             .service(get_info)
+            .service(get_plugins)
             .service(post_proxy_apisix_admin)
             .service(post_proxy_apisix_control)
+            .service(promotion::post_promotion)
             .service(static_files)
+            .wrap(from_fn(csrf::csrf_middleware))
             .wrap(cors)
-    })
-    .bind((address, port))?
+            .wrap(Condition::new(
+                is_tls,
+                DefaultHeaders::new().add(tls::HSTS_HEADER),
+            ))
+    });
+
+    match rustls_config {
+        Some(rustls_config) => server.bind_rustls_0_23((address, port), rustls_config)?,
+        None => server.bind((address, port))?,
+    }
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use actix_web::ResponseError;
+    use apisix_admin_panel_core::apisix::redaction::REDACTED;
+
+    use super::*;
+
+    fn test_client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn test_control_plane_timeout() {
+        // Accepts the connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/healthcheck", listener.local_addr().unwrap());
+
+        let err = fetch_control(test_client().get(&url)).await.unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[actix_web::test]
+    async fn test_control_plane_unreachable() {
+        let address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{address}/v1/healthcheck");
+
+        let err = fetch_control(test_client().get(&url)).await.unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_GATEWAY);
+    }
+
+    #[actix_web::test]
+    async fn test_create_with_masked_value() {
+        let cluster: Cluster =
+            serde_json::from_value(serde_json::json!({ "name": "test" })).unwrap();
+        let paths = sensitive_paths(Consumer::API_PREFIX, &PluginRegistry::new());
+        let opts = ProxyFetchOpts {
+            uri: Consumer::API_PREFIX.to_string(),
+            method: ProxyFetchMethod::POST,
+            data: Some(
+                serde_json::json!({
+                    "username": "foo",
+                    "plugins": { "key-auth": { "key": REDACTED } },
+                })
+                .to_string(),
+            ),
+        };
+
+        let err = restore_stored_values(&cluster, opts, &paths)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            err.to_string(),
+            "plugins.key-auth.key has the masked value, it must be set again"
+        );
+    }
+}
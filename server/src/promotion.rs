@@ -0,0 +1,171 @@
+use actix_web::{post, web, HttpRequest, HttpResponse};
+use apisix_admin_panel_core::{
+    apisix::{
+        common::prelude::*,
+        consumers::Consumer,
+        promotion::{
+            plan_promotion, ClusterItems, PromotionAction, PromotionChange, PromotionEntity,
+        },
+        redaction::{redact_at, redact_entity, sensitive_paths},
+    },
+    proxy::ProxyFetchOpts,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+
+use crate::{
+    auth::{verify_auth, verify_cluster, verify_role},
+    config::{Cluster, HandlerConfig, Role},
+    plugin_registry,
+    server_error::{CommonResponse, RespError},
+};
+
+// The source is the cluster selected in the request
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct PromotionRequest {
+    // When false, the changes are only compared with the target
+    apply: bool,
+    entity: PromotionEntity,
+    id: String,
+    target: String,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export)]
+struct PromotionResponse {
+    applied: bool,
+    changes: Vec<PromotionChange>,
+}
+
+async fn fetch_cluster_items(cluster: &Cluster) -> Result<ClusterItems, RespError> {
+    let mut items = ClusterItems::default();
+
+    for entity in PromotionEntity::ALL {
+        let opts = entity.get_all().map_err(RespError::Custom)?;
+        let text = cluster.handle_admin(opts).await.map_err(|_| {
+            RespError::Custom(format!("Error fetching data from cluster {}", cluster.name))
+        })?;
+
+        // Entities not supported by the cluster are considered empty
+        let response: Value = serde_json::from_str(&text).unwrap_or_default();
+
+        items.insert_list_response(entity, &response);
+    }
+
+    // The standalone config doesn't have credentials, and the APISIX versions before 3.7 answer
+    // with an error, which has no list
+    if cluster.standalone_config_path.is_none() {
+        let usernames: Vec<String> = items
+            .list(&PromotionEntity::Consumer)
+            .iter()
+            .filter_map(|consumer| consumer.get("username")?.as_str().map(str::to_string))
+            .collect();
+
+        for username in usernames {
+            let uri = format!("{}/{username}/credentials", Consumer::API_PREFIX);
+            let opts = ProxyFetchOpts::get(&uri).map_err(RespError::Custom)?;
+            let text = cluster.handle_admin(opts).await.map_err(|_| {
+                RespError::Custom(format!("Error fetching data from cluster {}", cluster.name))
+            })?;
+            let response: Value = serde_json::from_str(&text).unwrap_or_default();
+
+            items.insert_credentials(&username, &response);
+        }
+    }
+
+    Ok(items)
+}
+
+// The admin API returns the errors in the body with an `error_msg` key
+fn check_apply_response(text: &str) -> Result<(), String> {
+    let response: Value = serde_json::from_str(text).unwrap_or_default();
+
+    match response.get("error_msg").and_then(Value::as_str) {
+        Some(error_msg) => Err(error_msg.to_string()),
+        None => Ok(()),
+    }
+}
+
+/// Copies an entity and everything it references to the target cluster, in dependency order
+#[post("/api/promotion")]
+pub async fn post_promotion(
+    req: HttpRequest,
+    config: HandlerConfig,
+    body: web::Json<PromotionRequest>,
+) -> CommonResponse {
+    let source = verify_cluster(&req, &config)?;
+    let user = verify_auth(&req, &config)?;
+    let target = config.get_cluster(
+        Some(&body.target),
+        user.as_ref().map(|u| u.username.as_str()),
+    )?;
+
+    // The viewers can preview the changes but not apply them
+    if body.apply {
+        verify_role(user.as_ref(), Role::Admin)?;
+    }
+
+    if source.name == target.name {
+        return Err(
+            RespError::Custom("The source and target clusters are the same".to_string()).into(),
+        );
+    }
+
+    let source_items = fetch_cluster_items(source).await?;
+    let target_items = fetch_cluster_items(target).await?;
+
+    let changes = plan_promotion(&body.entity, &body.id, &source_items, &target_items)
+        .map_err(RespError::Custom)?;
+
+    if body.apply {
+        let pending = changes
+            .iter()
+            .filter(|c| c.action != PromotionAction::Unchanged);
+
+        for (idx, change) in pending.enumerate() {
+            let opts = change.apply().map_err(RespError::Custom)?;
+            let result = match target.handle_admin(opts).await {
+                Ok(text) => check_apply_response(&text),
+                Err(e) => Err(e.to_string()),
+            };
+
+            // The previous changes are kept, applying again only writes the remaining ones
+            result.map_err(|e| {
+                RespError::Custom(format!(
+                    "Failed to apply {:?} {} after {} changes: {}",
+                    change.entity, change.id, idx, e
+                ))
+            })?;
+        }
+    }
+
+    // The changes are applied with the stored values, the client only sees them masked
+    // Both clusters, the diffs include the plugins of the target
+    let mut registry = plugin_registry(&config, source);
+    registry.extend(plugin_registry(&config, target).into_definitions());
+    let changes = changes
+        .into_iter()
+        .map(|mut change| {
+            let paths = sensitive_paths(change.entity.api_prefix(), &registry);
+
+            redact_entity(&mut change.value, &paths);
+
+            for diff in &mut change.diff {
+                let at: Vec<&str> = diff.path.iter().map(String::as_str).collect();
+
+                for value in [&mut diff.source, &mut diff.target].into_iter().flatten() {
+                    redact_at(value, &at, &paths);
+                }
+            }
+
+            change
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(PromotionResponse {
+        applied: body.apply,
+        changes,
+    }))
+}
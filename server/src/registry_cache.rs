@@ -0,0 +1,79 @@
+use std::{collections::HashMap, sync::Mutex, time::SystemTime};
+
+use apisix_admin_panel_core::apisix::plugins::registry::PluginRegistry;
+
+use crate::config::Cluster;
+
+/// Plugin registries of each APISIX config file, built again when the file is modified
+#[derive(Default)]
+pub struct RegistryCache(Mutex<HashMap<String, (Option<SystemTime>, PluginRegistry)>>);
+
+impl RegistryCache {
+    pub fn get(&self, cluster: &Cluster, build: impl FnOnce() -> PluginRegistry) -> PluginRegistry {
+        let path = &cluster.config_file_path;
+        // A missing file has no time, so the registry is built again when it is created
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+        let cached = self.0.lock().ok().and_then(|registries| {
+            registries
+                .get(path)
+                .filter(|(cached_modified, _)| *cached_modified == modified)
+                .map(|(_, registry)| registry.clone())
+        });
+
+        if let Some(registry) = cached {
+            return registry;
+        }
+
+        let registry = build();
+
+        if let Ok(mut registries) = self.0.lock() {
+            registries.insert(path.clone(), (modified, registry.clone()));
+        }
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_built_again_when_modified() {
+        let path =
+            std::env::temp_dir().join(format!("apisix-panel-registry-{}.yaml", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let cluster: Cluster = serde_json::from_value(serde_json::json!({
+            "name": "test",
+            "config_file_path": path.to_str().unwrap(),
+        }))
+        .unwrap();
+
+        let cache = RegistryCache::default();
+        let builds = Cell::new(0);
+        let get = || {
+            cache.get(&cluster, || {
+                builds.set(builds.get() + 1);
+                PluginRegistry::new()
+            })
+        };
+
+        get();
+        get();
+        assert_eq!(builds.get(), 1);
+
+        std::fs::write(&path, "apisix: {}").unwrap();
+        get();
+        get();
+        assert_eq!(builds.get(), 2);
+
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(60))
+            .unwrap();
+        get();
+        assert_eq!(builds.get(), 3);
+    }
+}
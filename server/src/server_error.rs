@@ -3,15 +3,28 @@ use std::fmt::{self, Display};
 
 #[derive(Debug)]
 pub enum RespError {
+    // Invalid request, with the reason in the body
+    BadRequest(String),
     Unauthorized,
+    Forbidden,
+    // Forbidden, with the reason in the body
+    Denied(String),
+    NotFound(String),
     Custom(String),
+    // Error of an APISIX API, with its status code and body
+    Upstream(u16, String),
 }
 
 impl Display for RespError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::BadRequest(msg) => write!(f, "{}", msg),
             Self::Unauthorized => write!(f, "Unauthorized"),
+            Self::Forbidden => write!(f, "Forbidden"),
+            Self::Denied(msg) => write!(f, "{}", msg),
+            Self::NotFound(msg) => write!(f, "{}", msg),
             Self::Custom(msg) => write!(f, "{}", msg),
+            Self::Upstream(_, msg) => write!(f, "{}", msg),
         }
     }
 }
@@ -19,15 +32,26 @@ impl Display for RespError {
 impl ResponseError for RespError {
     fn status_code(&self) -> StatusCode {
         match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden | Self::Denied(_) => StatusCode::FORBIDDEN,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::Custom(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Upstream(status, _) => {
+                StatusCode::from_u16(*status).unwrap_or(StatusCode::BAD_GATEWAY)
+            }
         }
     }
 
     fn error_response(&self) -> HttpResponse<BoxBody> {
         match self {
+            Self::BadRequest(msg) => HttpResponse::BadRequest().body(msg.to_string()),
             Self::Unauthorized => HttpResponse::Unauthorized().finish(),
+            Self::Forbidden => HttpResponse::Forbidden().finish(),
+            Self::Denied(msg) => HttpResponse::Forbidden().body(msg.to_string()),
+            Self::NotFound(msg) => HttpResponse::NotFound().body(msg.to_string()),
             Self::Custom(msg) => HttpResponse::InternalServerError().body(msg.to_string()),
+            Self::Upstream(_, msg) => HttpResponse::build(self.status_code()).body(msg.to_string()),
         }
     }
 }
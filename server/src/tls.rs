@@ -0,0 +1,119 @@
+use rustls::{
+    crypto::{ring, CryptoProvider},
+    server::{ClientHello, ResolvesServerCert},
+    sign::CertifiedKey,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::BufReader,
+    sync::{Arc, RwLock},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+pub const HSTS_HEADER: (&str, &str) = ("Strict-Transport-Security", "max-age=31536000");
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert_path: String,
+    pub key_path: String,
+}
+
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(ring::default_provider())
+}
+
+/// Reads the PEM files, the certificate file can include the chain after the certificate
+pub fn load_certified_key(tls: &TlsConfig) -> Result<CertifiedKey, String> {
+    let open = |path: &str| {
+        File::open(path)
+            .map(BufReader::new)
+            .map_err(|e| format!("Failed to open {path}: {e}"))
+    };
+
+    let certs = rustls_pemfile::certs(&mut open(&tls.cert_path)?)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid certificate {}: {e}", tls.cert_path))?;
+
+    if certs.is_empty() {
+        return Err(format!("No certificate found in {}", tls.cert_path));
+    }
+
+    let key = rustls_pemfile::private_key(&mut open(&tls.key_path)?)
+        .map_err(|e| format!("Invalid private key {}: {e}", tls.key_path))?
+        .ok_or_else(|| format!("No private key found in {}", tls.key_path))?;
+
+    let signing_key = provider()
+        .key_provider
+        .load_private_key(key)
+        .map_err(|e| format!("Unsupported private key {}: {e}", tls.key_path))?;
+
+    Ok(CertifiedKey::new(certs, signing_key))
+}
+
+fn modified_times(tls: &TlsConfig) -> Option<(SystemTime, SystemTime)> {
+    let modified = |path: &str| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    Some((modified(&tls.cert_path)?, modified(&tls.key_path)?))
+}
+
+// Serves the last valid certificate, so renewing it doesn't require restarting the server
+#[derive(Debug)]
+struct ReloadingCertResolver {
+    current: RwLock<Arc<CertifiedKey>>,
+}
+
+impl ResolvesServerCert for ReloadingCertResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        self.current.read().ok().map(|key| key.clone())
+    }
+}
+
+fn watch_files(tls: TlsConfig, resolver: Arc<ReloadingCertResolver>) {
+    let mut last_modified = modified_times(&tls);
+
+    thread::spawn(move || loop {
+        thread::sleep(RELOAD_INTERVAL);
+
+        let modified = modified_times(&tls);
+
+        if modified.is_none() || modified == last_modified {
+            continue;
+        }
+
+        // The files may be written one after the other, a failed load is retried in the next check
+        match load_certified_key(&tls) {
+            Ok(key) => {
+                if let Ok(mut current) = resolver.current.write() {
+                    *current = Arc::new(key);
+                }
+
+                last_modified = modified;
+                println!("Reloaded the TLS certificate {}", tls.cert_path);
+            }
+            Err(e) => eprintln!("Failed to reload the TLS certificate: {e}"),
+        }
+    });
+}
+
+pub fn rustls_config(tls: &TlsConfig) -> Result<rustls::ServerConfig, String> {
+    let resolver = Arc::new(ReloadingCertResolver {
+        current: RwLock::new(Arc::new(load_certified_key(tls)?)),
+    });
+
+    watch_files(tls.clone(), resolver.clone());
+
+    let mut config = rustls::ServerConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| e.to_string())?
+        .with_no_client_auth()
+        .with_cert_resolver(resolver);
+
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(config)
+}
@@ -1,7 +1,23 @@
 use crate::{macros::derive_common, proxy::WasmProxyFetchOpts};
-use apisix_admin_panel_core::apisix::control_plane::ControlPlane;
+use apisix_admin_panel_core::apisix::{control_plane::ControlPlane, schema::ApisixSchema};
+use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::prelude::*;
 
+thread_local! {
+    static LOADED_SCHEMA: RefCell<Option<Rc<ApisixSchema>>> = const { RefCell::new(None) };
+    static APISIX_VERSION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// The schema of the running APISIX, when it was loaded it replaces the static plugin definitions
+pub fn loaded_schema() -> Option<Rc<ApisixSchema>> {
+    LOADED_SCHEMA.with(|schema| schema.borrow().clone())
+}
+
+// Version of the connected APISIX, from `ServerInfo`
+pub fn apisix_version() -> Option<String> {
+    APISIX_VERSION.with(|version| version.borrow().clone())
+}
+
 derive_common! {
 pub struct WasmControlPlane(ControlPlane);}
 
@@ -11,10 +27,74 @@ impl WasmControlPlane {
         Ok(ControlPlane::get_health_check()?.into())
     }
 
+    pub fn get_entity_health_check(
+        src_type: String,
+        id: String,
+    ) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_entity_health_check(&src_type, &id)?.into())
+    }
+
     pub fn get_schema() -> Result<WasmProxyFetchOpts, String> {
         Ok(ControlPlane::get_schema()?.into())
     }
 
+    pub fn get_routes() -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_routes()?.into())
+    }
+
+    pub fn get_route(id: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_route(&id)?.into())
+    }
+
+    pub fn get_services() -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_services()?.into())
+    }
+
+    pub fn get_service(id: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_service(&id)?.into())
+    }
+
+    pub fn get_upstreams() -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_upstreams()?.into())
+    }
+
+    pub fn get_upstream(id: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_upstream(&id)?.into())
+    }
+
+    pub fn get_plugin_metadatas() -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_plugin_metadatas()?.into())
+    }
+
+    pub fn get_plugin_metadata(name: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_plugin_metadata(&name)?.into())
+    }
+
+    pub fn get_discovery_dump(discovery: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_discovery_dump(&discovery)?.into())
+    }
+
+    pub fn get_discovery_dump_file(discovery: String) -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::get_discovery_dump_file(&discovery)?.into())
+    }
+
+    pub fn run_gc() -> Result<WasmProxyFetchOpts, String> {
+        Ok(ControlPlane::run_gc()?.into())
+    }
+
+    pub fn load_schema(response: JsValue) -> Result<(), String> {
+        let schema: ApisixSchema =
+            serde_wasm_bindgen::from_value(response).map_err(|e| e.to_string())?;
+
+        LOADED_SCHEMA.with(|loaded| loaded.replace(Some(Rc::new(schema))));
+
+        Ok(())
+    }
+
+    pub fn set_apisix_version(version: Option<String>) {
+        APISIX_VERSION.with(|current| current.replace(version));
+    }
+
     pub fn reload_plugins() -> Result<WasmProxyFetchOpts, String> {
         Ok(ControlPlane::reload_plugins()?.into())
     }
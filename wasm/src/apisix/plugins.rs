@@ -1,49 +1,73 @@
-use apisix_admin_panel_core::apisix::plugins::{
-    basic_auth::{ConsumerBasicAuthPlugin, RouteBasicAuthPlugin},
-    consumer_restriction::ConsumerRestrictionPlugin,
-    cors::CorsPlugin,
-    ext_plugin_pre_req::ExtPluginPreReqPlugin,
-    ip_restriction::IpRestrictionPlugin,
-    key_auth::{ConsumerKeyAuthPlugin, RouteKeyAuthPlugin},
-    limit_count::LimitCountPlugin,
-    prometheus::PrometheusPlugin,
-    proxy_mirror::ProxyMirrorPlugin,
-    proxy_rewrite::ProxyRewritePlugin,
-    public_api::PublicApiPlugin,
-    response_rewrite::ResponseRewritePlugin,
-    uri_blocker::UriBlockerPlugin,
+use super::control_plane::{apisix_version, loaded_schema};
+use crate::proxy::WasmProxyFetchOpts;
+use apisix_admin_panel_core::apisix::{
+    effective_plugins::{effective_plugins, get_plugin_configs, PluginSources},
+    plugins::{
+        common::PluginDefinition, registry::PluginRegistry, wasm_plugins::wasm_plugin_definitions,
+    },
 };
 use serde::Serialize;
-use serde_json::json;
 use serde_wasm_bindgen::Serializer;
+use std::cell::RefCell;
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
+thread_local! {
+    static WASM_PLUGINS: RefCell<Vec<PluginDefinition>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn plugin_registry() -> PluginRegistry {
+    let mut registry = PluginRegistry::new();
+
+    WASM_PLUGINS.with(|plugins| registry.extend(plugins.borrow().iter().cloned()));
+
+    match apisix_version() {
+        Some(version) => registry.for_version(&version),
+        None => registry,
+    }
+}
+
 #[wasm_bindgen]
 pub struct WasmPluginDefinitions;
 
 #[wasm_bindgen]
 impl WasmPluginDefinitions {
     pub fn print() -> JsValue {
-        let definitions = json!([
-            ConsumerBasicAuthPlugin::new(),
-            ConsumerKeyAuthPlugin::new(),
-            ConsumerRestrictionPlugin::new(),
-            CorsPlugin::new(),
-            ExtPluginPreReqPlugin::new(),
-            IpRestrictionPlugin::new(),
-            LimitCountPlugin::new(),
-            PrometheusPlugin::new(),
-            ProxyMirrorPlugin::new(),
-            ProxyRewritePlugin::new(),
-            PublicApiPlugin::new(),
-            ResponseRewritePlugin::new(),
-            RouteBasicAuthPlugin::new(),
-            RouteKeyAuthPlugin::new(),
-            UriBlockerPlugin::new()
-        ]);
+        let definitions = plugin_registry()
+            .client_definitions(loaded_schema().as_deref(), apisix_version().as_deref());
 
         let serializer = Serializer::new().serialize_maps_as_objects(true);
 
         definitions.serialize(&serializer).unwrap()
     }
+
+    // Receives the response of `/api/apisix-config`, to add the proxy-wasm plugins it declares
+    pub fn load_file_config(config: JsValue) -> Result<(), String> {
+        let config: serde_json::Value =
+            serde_wasm_bindgen::from_value(config).map_err(|e| e.to_string())?;
+
+        WASM_PLUGINS.with(|plugins| plugins.replace(wasm_plugin_definitions(&config)));
+
+        Ok(())
+    }
+
+    pub fn get_plugin_configs() -> Result<WasmProxyFetchOpts, String> {
+        Ok(get_plugin_configs()?.into())
+    }
+
+    // The sources are the list responses of the entities, see `PluginSources`
+    pub fn effective_plugins(
+        route_id: String,
+        consumer: Option<String>,
+        sources: JsValue,
+    ) -> Result<JsValue, String> {
+        let sources: PluginSources =
+            serde_wasm_bindgen::from_value(sources).map_err(|e| e.to_string())?;
+        let schema = loaded_schema();
+        let plugins =
+            effective_plugins(&route_id, consumer.as_deref(), &sources, schema.as_deref())?;
+
+        let serializer = Serializer::new().serialize_maps_as_objects(true);
+
+        plugins.serialize(&serializer).map_err(|e| e.to_string())
+    }
 }
@@ -1,4 +1,6 @@
-use crate::macros::{derive_common, entity_fields_impl, entity_impl, entity_list_impl};
+use crate::macros::{
+    derive_common, entity_fields_impl, entity_impl, entity_list_impl, upstream_references_impl,
+};
 use apisix_admin_panel_core::apisix::routes::{GetRoutesResponse, Route, RouteEntity};
 
 derive_common! {
@@ -10,3 +12,4 @@ pub struct WasmGetRoutesResponse(GetRoutesResponse);}
 entity_impl! {WasmRoute, Route}
 entity_list_impl! {WasmGetRoutesResponse, WasmRoute}
 entity_fields_impl! {WasmRoute, RouteEntity, WasmGetRoutesResponse, Route}
+upstream_references_impl! {WasmRoute}
@@ -1,5 +1,8 @@
 use crate::macros::{derive_common, entity_fields_impl, entity_impl, entity_list_impl};
-use apisix_admin_panel_core::apisix::upstreams::{GetUpstreamsResponse, Upstream, UpstreamEntity};
+use apisix_admin_panel_core::apisix::upstreams::{
+    GetUpstreamsResponse, Upstream, UpstreamEntity, UpstreamNode, UpstreamNodes,
+};
+use wasm_bindgen::prelude::*;
 
 derive_common! {
 pub struct WasmUpstream(UpstreamEntity);}
@@ -10,3 +13,48 @@ pub struct WasmGetUpstreamsResponse(GetUpstreamsResponse);}
 entity_impl! {WasmUpstream, Upstream}
 entity_list_impl! {WasmGetUpstreamsResponse, WasmUpstream}
 entity_fields_impl! {WasmUpstream, UpstreamEntity, WasmGetUpstreamsResponse, Upstream}
+
+#[wasm_bindgen]
+impl WasmGetUpstreamsResponse {
+    #[wasm_bindgen(getter)]
+    pub fn ids(&self) -> Vec<String> {
+        self.0
+            .list
+            .iter()
+            .map(|u| u.parsed.value.0.get_str("id"))
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+impl WasmUpstream {
+    /// Returns the nodes in the array form, even if they are stored as a hash table
+    pub fn get_nodes(&self) -> Result<JsValue, String> {
+        let nodes = match self.0.parsed.value.0.get("nodes") {
+            Some(value) if !value.is_null() => {
+                serde_json::from_value::<UpstreamNodes>(value.clone())
+                    .map_err(|e| format!("Invalid nodes: {}", e))?
+                    .to_list()?
+            }
+            _ => vec![],
+        };
+
+        serde_wasm_bindgen::to_value(&nodes).map_err(|e| e.to_string())
+    }
+
+    pub fn set_nodes(&mut self, nodes: JsValue) -> Result<(), String> {
+        let nodes: Vec<UpstreamNode> =
+            serde_wasm_bindgen::from_value(nodes).map_err(|e| format!("Invalid nodes: {}", e))?;
+
+        if nodes.is_empty() {
+            self.0.parsed.value.0.remove("nodes".to_string());
+            return Ok(());
+        }
+
+        let value = serde_json::to_value(UpstreamNodes::List(nodes)).map_err(|e| e.to_string())?;
+
+        self.0.parsed.value.0.insert("nodes".to_string(), value);
+
+        Ok(())
+    }
+}
@@ -31,6 +31,22 @@ macro_rules! entity_impl {
                 $entity_struct::DOCS_KEY.to_string()
             }
 
+            #[wasm_bindgen(getter)]
+            pub fn required_version() -> Option<String> {
+                $entity_struct::REQUIRED_VERSION.map(|v| v.to_string())
+            }
+
+            // Receives the version instead of using the stored one, to be usable while rendering
+            pub fn is_supported(version: Option<String>) -> bool {
+                match (version, $entity_struct::REQUIRED_VERSION) {
+                    (Some(version), Some(required)) => {
+                        apisix_admin_panel_core::apisix::base::VersionRange::since(required)
+                            .contains(&version)
+                    }
+                    _ => true,
+                }
+            }
+
             #[wasm_bindgen(getter)]
             pub fn plugin_entity() -> wasm_bindgen::JsValue {
                 serde_wasm_bindgen::to_value(&$entity_struct::PLUGIN_ENTITY).unwrap()
@@ -93,11 +109,36 @@ macro_rules! entity_impl {
                 self.0.delete().map(Into::into)
             }
 
+            fn validation_result(
+                &self,
+            ) -> Result<(), Vec<apisix_admin_panel_core::apisix::validation::FieldError>> {
+                match crate::apisix::control_plane::loaded_schema() {
+                    Some(schema) if $entity_struct::SCHEMA_KEY.is_some() => {
+                        self.0.validate_with_schema(&schema)
+                    }
+                    _ => self.0.validate(&crate::apisix::plugins::plugin_registry()),
+                }
+            }
+
+            pub fn validate(&self) -> wasm_bindgen::JsValue {
+                let errors = self.validation_result().err().unwrap_or_default();
+
+                serde_wasm_bindgen::to_value(&errors).unwrap()
+            }
+
+            fn check_valid(&self) -> Result<(), String> {
+                self.validation_result().map_err(|errors| {
+                    apisix_admin_panel_core::apisix::validation::FieldError::join(&errors)
+                })
+            }
+
             pub fn create(&self) -> Result<crate::proxy::WasmProxyFetchOpts, String> {
+                self.check_valid()?;
                 self.0.create().map(Into::into)
             }
 
             pub fn update(&self) -> Result<crate::proxy::WasmProxyFetchOpts, String> {
+                self.check_valid()?;
                 self.0.update().map(Into::into)
             }
         }
@@ -187,6 +228,47 @@ macro_rules! entity_fields_impl {
                     .insert("plugins".to_string(), json_val);
             }
 
+            /// Sets `_meta.disable` in the config of a plugin, keeping the rest of the config
+            pub fn set_plugin_disabled(&mut self, name: String, disabled: bool) -> Result<(), String> {
+                if $core_struct::PLUGIN_ENTITY.is_none() {
+                    return Err("This entity doesn't support plugins".to_string());
+                }
+
+                let mut plugins = self
+                    .0
+                    .parsed
+                    .value
+                    .0
+                    .get("plugins")
+                    .and_then(|p| p.as_object())
+                    .cloned()
+                    .ok_or_else(|| format!("Plugin {} is not configured", name))?;
+
+                apisix_admin_panel_core::apisix::plugins::meta::set_plugin_disabled(
+                    &mut plugins,
+                    &name,
+                    disabled,
+                )?;
+
+                self.0
+                    .parsed
+                    .value
+                    .0
+                    .insert("plugins".to_string(), serde_json::Value::Object(plugins));
+
+                Ok(())
+            }
+
+            pub fn is_plugin_disabled(&self, name: String) -> bool {
+                self.0
+                    .parsed
+                    .value
+                    .0
+                    .get("plugins")
+                    .and_then(|p| p.get(&name))
+                    .is_some_and(apisix_admin_panel_core::apisix::plugins::meta::PluginMeta::is_disabled)
+            }
+
             pub fn get_field(&mut self, key: String) -> wasm_bindgen::prelude::JsValue {
                 let json_val = self.0.parsed.value.0.get(&key).clone();
 
@@ -194,7 +276,20 @@ macro_rules! entity_fields_impl {
             }
 
             pub fn fields_definitions() -> wasm_bindgen::prelude::JsValue {
-                serde_wasm_bindgen::to_value(&$base_struct::value_fields()).unwrap()
+                let version = crate::apisix::control_plane::apisix_version();
+                let fields = $base_struct::value_fields()
+                    .into_iter()
+                    .filter(|f| {
+                        version.as_ref().is_none_or(|v| {
+                            apisix_admin_panel_core::apisix::base::is_version_available(
+                                &f.version_range,
+                                v,
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                serde_wasm_bindgen::to_value(&fields).unwrap()
             }
 
             pub fn get_all_response(
@@ -244,3 +339,40 @@ macro_rules! entity_fields_impl {
 }
 
 pub(crate) use entity_fields_impl;
+
+// The entities that can have the `traffic-split` plugin
+macro_rules! upstream_references_impl {
+    ($wasm_struct: ident) => {
+        #[wasm_bindgen::prelude::wasm_bindgen]
+        impl $wasm_struct {
+            /// Checks that the `traffic-split` plugin only references upstreams from `upstream_ids`
+            pub fn check_upstream_references(&self, upstream_ids: Vec<String>) -> Result<(), String> {
+                let mut errors = vec![];
+                let config = self
+                    .0
+                    .parsed
+                    .value
+                    .0
+                    .get("plugins")
+                    .and_then(|p| p.get("traffic-split"));
+
+                if let Some(config) = config {
+                    apisix_admin_panel_core::apisix::plugins::traffic_split::TrafficSplitPlugin::validate_upstream_ids(
+                        config,
+                        &upstream_ids,
+                        "plugins.traffic-split",
+                        &mut errors,
+                    );
+                }
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(apisix_admin_panel_core::apisix::validation::FieldError::join(&errors))
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use upstream_references_impl;
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthCheckHealthy } from "./HealthCheckHealthy";
+import type { HealthCheckType } from "./HealthCheckType";
+import type { HealthCheckUnhealthy } from "./HealthCheckUnhealthy";
+
+export type ActiveHealthCheck = {
+  concurrency: null | number;
+  healthy: HealthCheckHealthy | null;
+  host: null | string;
+  http_path: null | string;
+  https_verify_certificate: boolean | null;
+  port: null | number;
+  req_headers: null | string[];
+  timeout: null | number;
+  type: HealthCheckType | null;
+  unhealthy: HealthCheckUnhealthy | null;
+};
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Role } from "./Role";
+
+export type ApiToken = {
+  created_at: number;
+  expires_at: null | number;
+  id: string;
+  name: string;
+  role: Role;
+  username: string;
+  write: string[];
+};
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ControlHealthCheckCounter = {
+  http_failure: number;
+  success: number;
+  tcp_failure: number;
+  timeout_failure: number;
+};
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ControlHealthCheckCounter } from "./ControlHealthCheckCounter";
+import type { ControlNodeStatus } from "./ControlNodeStatus";
+
+export type ControlHealthCheckNode = {
+  counter: ControlHealthCheckCounter;
+  hostname: null | string;
+  ip: string;
+  port: number;
+  status: ControlNodeStatus;
+};
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ControlItem = {
+  createdIndex: null | number;
+  key: string;
+  modifiedIndex: null | number;
+  value: unknown;
+};
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PluginSource } from "./PluginSource";
+
+export type EffectivePlugin = {
+  config: unknown;
+  disabled: boolean;
+  name: string;
+  overridden: PluginSource[];
+  priority: null | number;
+  source: PluginSource;
+};
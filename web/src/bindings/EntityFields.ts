@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PropertyType } from "./PropertyType";
 import type { Required } from "./Required";
+import type { VersionRange } from "./VersionRange";
 
 export type EntityFields = {
   default_value: null | string;
@@ -11,4 +12,6 @@ export type EntityFields = {
   is_required: Required;
   name: string;
   property_type: PropertyType;
+  sensitive: boolean;
+  version_range: null | VersionRange;
 };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActiveHealthCheck } from "./ActiveHealthCheck";
+import type { PassiveHealthCheck } from "./PassiveHealthCheck";
+
+export type HealthCheck = {
+  active: ActiveHealthCheck | null;
+  passive: null | PassiveHealthCheck;
+};
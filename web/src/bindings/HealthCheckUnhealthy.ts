@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HealthCheckUnhealthy = {
+  http_failures: null | number;
+  http_statuses: null | number[];
+  interval: null | number;
+  tcp_failures: null | number;
+  timeouts: null | number;
+};
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthCheckHealthy } from "./HealthCheckHealthy";
+import type { HealthCheckType } from "./HealthCheckType";
+import type { HealthCheckUnhealthy } from "./HealthCheckUnhealthy";
+
+export type PassiveHealthCheck = {
+  healthy: HealthCheckHealthy | null;
+  type: HealthCheckType | null;
+  unhealthy: HealthCheckUnhealthy | null;
+};
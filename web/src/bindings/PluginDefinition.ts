@@ -1,9 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PluginEntities } from "./PluginEntities";
 import type { PluginOption } from "./PluginOption";
+import type { VersionRange } from "./VersionRange";
 
 export type PluginDefinition = {
   entities: PluginEntities[];
   name: string;
   options: PluginOption[];
+  version_range: null | VersionRange;
 };
@@ -1,11 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PropertyType } from "./PropertyType";
 import type { Required } from "./Required";
+import type { VersionRange } from "./VersionRange";
 
 export type PluginOption = {
   default_value: null | string;
   description: string;
   is_required: Required;
+  is_secret: boolean;
   name: string;
   property_type: PropertyType;
+  sensitive: boolean;
+  version_range: null | VersionRange;
 };
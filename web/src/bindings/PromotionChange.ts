@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromotionAction } from "./PromotionAction";
+import type { PromotionDiff } from "./PromotionDiff";
+import type { PromotionEntity } from "./PromotionEntity";
+
+export type PromotionChange = {
+  action: PromotionAction;
+  diff: PromotionDiff[];
+  entity: PromotionEntity;
+  id: string;
+  unresolved: string[];
+  value: unknown;
+};
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PromotionEntity =
+  | "Consumer"
+  | "ConsumerGroup"
+  | "Credential"
+  | "PluginConfig"
+  | "Route"
+  | "Service"
+  | "Ssl"
+  | "Upstream";
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromotionEntity } from "./PromotionEntity";
+
+export type PromotionRequest = {
+  apply: boolean;
+  entity: PromotionEntity;
+  id: string;
+  target: string;
+};
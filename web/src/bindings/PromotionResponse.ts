@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromotionChange } from "./PromotionChange";
+
+export type PromotionResponse = {
+  applied: boolean;
+  changes: PromotionChange[];
+};
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PropertyType } from "./PropertyType";
+import type { Required } from "./Required";
+
+export type PropertyField = {
+  default_value: null | string;
+  description: string;
+  is_required: Required;
+  name: string;
+  property_type: PropertyType;
+  sensitive: boolean;
+};
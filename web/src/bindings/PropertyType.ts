@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PropertyField } from "./PropertyField";
 
 export type PropertyType =
   | "Boolean"
@@ -6,5 +7,8 @@ export type PropertyType =
   | "Number"
   | "Plugins"
   | "String"
+  | { "Code": string }
   | { "Enum": string[] }
-  | { "List": PropertyType };
+  | { "List": PropertyType }
+  | { "Map": PropertyType }
+  | { "Object": PropertyField[] };
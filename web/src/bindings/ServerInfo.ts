@@ -1,7 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ClusterInfo } from "./ClusterInfo";
+import type { Role } from "./Role";
 
 export type ServerInfo = {
   apisix_url: string;
+  apisix_version: null | string;
+  cluster: string;
+  clusters: ClusterInfo[];
   has_auth: boolean;
   is_standalone: boolean;
+  role: null | Role;
+  username: null | string;
 };
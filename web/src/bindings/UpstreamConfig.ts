@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HashOn } from "./HashOn";
+import type { HealthCheck } from "./HealthCheck";
+import type { KeepalivePool } from "./KeepalivePool";
+import type { PassHost } from "./PassHost";
+import type { UpstreamNodes } from "./UpstreamNodes";
+import type { UpstreamTimeout } from "./UpstreamTimeout";
+import type { UpstreamTls } from "./UpstreamTls";
+import type { UpstreamType } from "./UpstreamType";
+
+export type UpstreamConfig = {
+  checks: HealthCheck | null;
+  discovery_type: null | string;
+  hash_on: HashOn | null;
+  keepalive_pool: KeepalivePool | null;
+  key: null | string;
+  nodes: null | UpstreamNodes;
+  pass_host: null | PassHost;
+  retries: null | number;
+  retry_timeout: null | number;
+  service_name: null | string;
+  timeout: null | UpstreamTimeout;
+  tls: null | UpstreamTls;
+  type: null | UpstreamType;
+  upstream_host: null | string;
+};
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpstreamTls = {
+  client_cert: null | string;
+  client_cert_id: null | string;
+  client_key: null | string;
+  verify: boolean | null;
+};
@@ -3,6 +3,8 @@ import { useRouter } from "next/router";
 import {
   WasmConsumer,
   WasmConsumerGroup,
+  WasmControlPlane,
+  WasmPluginDefinitions,
   WasmRoute,
   WasmSecret,
   WasmService,
@@ -18,6 +20,8 @@ import DialogActions from "@mui/material/DialogActions";
 import DialogContent from "@mui/material/DialogContent";
 import DialogContentText from "@mui/material/DialogContentText";
 import DialogTitle from "@mui/material/DialogTitle";
+import MenuItem from "@mui/material/MenuItem";
+import Select from "@mui/material/Select";
 import Snackbar from "@mui/material/Snackbar";
 
 import {
@@ -35,13 +39,22 @@ import {
   deleteService,
   deleteStreamRoute,
   deleteUpstream,
+  getControlRoutes,
+  getControlServices,
+  getControlUpstreams,
   getFileConfig,
   getHealthCheck,
+  getPluginConfigs,
+  getPluginMetadatas,
   getSchema,
+  loadFileConfig,
+  loadSchema,
   reloadPlugins,
+  runGc,
   logout,
+  selectCluster,
 } from "../lib/client";
-import { prepareEdit } from "../lib/parsing";
+import { listValues, prepareEdit } from "../lib/parsing";
 import {
   useFetchConsumerGroups,
   useFetchConsumers,
@@ -56,6 +69,9 @@ import {
 import type { EntityFieldsItems } from "./EntityField";
 import { EntityField, parseEntityFields } from "./EntityField";
 import Layout from "./Layout";
+import { refreshPluginsDefinitions } from "./PluginsField";
+import type { PromotionTarget } from "./PromotionDialog";
+import { PromotionDialog } from "./PromotionDialog";
 import { Form, Section, SubmitButton } from "./Section";
 import Button from "./ui/Button";
 import { EntitiesList } from "./ui/List";
@@ -121,6 +137,8 @@ export default function Dashboard() {
     useSnackbar();
 
   const [controlData, setControlData] = useState<unknown>(null);
+  const [effectivePlugins, setEffectivePlugins] = useState<unknown>(null);
+  const [promotion, setPromotion] = useState<PromotionTarget | null>(null);
 
   const [consumerFields, setConsumerFields] = useState<EntityFieldsItems>(null);
   const [routeFields, setRouteFields] = useState<EntityFieldsItems>(null);
@@ -146,6 +164,30 @@ export default function Dashboard() {
     setRendered(true);
   }, []);
 
+  const isStandalone = serverInfo?.is_standalone;
+  const hasServerInfo = !!serverInfo;
+  const apisixVersion = serverInfo?.apisix_version;
+  const canPromote = (serverInfo?.clusters.length || 0) > 1;
+
+  useEffect(() => {
+    if (apisixVersion) {
+      WasmControlPlane.set_apisix_version(apisixVersion);
+      refreshPluginsDefinitions();
+    }
+  }, [apisixVersion]);
+
+  useEffect(() => {
+    if (hasServerInfo) {
+      loadFileConfig().then(refreshPluginsDefinitions);
+    }
+  }, [hasServerInfo]);
+
+  useEffect(() => {
+    if (isStandalone === false) {
+      loadSchema().then(refreshPluginsDefinitions);
+    }
+  }, [isStandalone]);
+
   if (!rendered) {
     return null;
   }
@@ -190,6 +232,25 @@ export default function Dashboard() {
       </Head>
       <Layout>
         <div className="flex flex-col gap-[24px]">
+          {serverInfo && serverInfo.clusters.length > 1 && (
+            <div className="flex flex-row items-center gap-[12px] px-[8px]">
+              <label htmlFor="cluster-select">Cluster:</label>
+              <Select
+                id="cluster-select"
+                onChange={(e) => {
+                  selectCluster(e.target.value);
+                }}
+                value={serverInfo.cluster}
+              >
+                {serverInfo.clusters.map((cluster) => (
+                  <MenuItem key={cluster.name} value={cluster.name}>
+                    {cluster.name}
+                    {cluster.is_standalone ? " (standalone)" : ""}
+                  </MenuItem>
+                ))}
+              </Select>
+            </div>
+          )}
           <div>
             <Section
               title={
@@ -199,7 +260,7 @@ export default function Dashboard() {
                 </h2>
               }
             >
-              <div className="flex flex-row gap-[12px]">
+              <div className="flex flex-row flex-wrap gap-[12px]">
                 {serverInfo && !serverInfo.is_standalone && (
                   <>
                     <Button
@@ -218,6 +279,42 @@ export default function Dashboard() {
                     >
                       Get health check
                     </Button>
+                    <Button
+                      onClick={() => {
+                        getControlRoutes()
+                          .then(setControlData)
+                          .catch(setSnackbar);
+                      }}
+                    >
+                      Get loaded routes
+                    </Button>
+                    <Button
+                      onClick={() => {
+                        getControlServices()
+                          .then(setControlData)
+                          .catch(setSnackbar);
+                      }}
+                    >
+                      Get loaded services
+                    </Button>
+                    <Button
+                      onClick={() => {
+                        getControlUpstreams()
+                          .then(setControlData)
+                          .catch(setSnackbar);
+                      }}
+                    >
+                      Get loaded upstreams
+                    </Button>
+                    <Button
+                      onClick={() => {
+                        getPluginMetadatas()
+                          .then(setControlData)
+                          .catch(setSnackbar);
+                      }}
+                    >
+                      Get plugin metadata
+                    </Button>
                     <Button
                       onClick={() => {
                         reloadPlugins()
@@ -229,6 +326,15 @@ export default function Dashboard() {
                     >
                       Reload plugins
                     </Button>
+                    <Button
+                      onClick={() => {
+                        runGc()
+                          .then(() => setSnackbar("Garbage collection done"))
+                          .catch(setSnackbar);
+                      }}
+                    >
+                      Run GC
+                    </Button>
                   </>
                 )}
                 <Button
@@ -319,6 +425,16 @@ export default function Dashboard() {
                     setConsumerFields(prepareEdit(consumer, WasmConsumer));
                     setFormOpened(WasmConsumer)(true, true);
                   }}
+                  onPromote={
+                    canPromote
+                      ? (consumer) => {
+                          setPromotion({
+                            entity: "Consumer",
+                            id: String(consumer.get_field("username")),
+                          });
+                        }
+                      : undefined
+                  }
                 />
               )}
             </Section>
@@ -388,6 +504,16 @@ export default function Dashboard() {
 
                     setFormOpened(WasmConsumerGroup)(true, true);
                   }}
+                  onPromote={
+                    canPromote
+                      ? (consumerGroup) => {
+                          setPromotion({
+                            entity: "ConsumerGroup",
+                            id: String(consumerGroup.get_field("id")),
+                          });
+                        }
+                      : undefined
+                  }
                 />
               )}
             </Section>
@@ -405,6 +531,11 @@ export default function Dashboard() {
                   const route = parseEntityFields(WasmRoute, routeFields);
 
                   Promise.resolve()
+                    .then(
+                      () =>
+                        upstreams &&
+                        route.check_upstream_references(upstreams.ids),
+                    )
                     .then(() => upsertRoute(route, getIsEditing(WasmRoute)))
                     .then(() => refetchRoutes())
                     .then(() => {
@@ -443,6 +574,27 @@ export default function Dashboard() {
                     setRouteFields(prepareEdit(route, WasmRoute));
                     setFormOpened(WasmRoute)(true, true);
                   }}
+                  onInspectPlugins={(route) => {
+                    getPluginConfigs()
+                      .catch(() => ({ list: [] }))
+                      .then((pluginConfigs) =>
+                        WasmPluginDefinitions.effective_plugins(
+                          String(route.get_field("id")),
+                          undefined,
+                          {
+                            consumer_groups: listValues(consumerGroups?.list),
+                            consumers: listValues(consumers?.list),
+                            plugin_configs:
+                              (pluginConfigs as { list?: unknown[] }).list ||
+                              [],
+                            routes: listValues(routes.list),
+                            services: listValues(services?.list),
+                          },
+                        ),
+                      )
+                      .then(setEffectivePlugins)
+                      .catch(setSnackbar);
+                  }}
                   onOpenUrl={
                     serverInfo?.apisix_url
                       ? (route) => {
@@ -462,8 +614,30 @@ export default function Dashboard() {
                         }
                       : undefined
                   }
+                  onPromote={
+                    canPromote
+                      ? (route) => {
+                          setPromotion({
+                            entity: "Route",
+                            id: String(route.get_field("id")),
+                          });
+                        }
+                      : undefined
+                  }
                 />
               )}
+              {!!effectivePlugins && (
+                <div>
+                  <IconButton
+                    aria-label="Clear"
+                    edge="end"
+                    onClick={() => setEffectivePlugins(null)}
+                  >
+                    <DeleteIcon />
+                  </IconButton>
+                  <ReactJson src={effectivePlugins} theme="monokai" />
+                </div>
+              )}
             </Section>
             <Section
               title={
@@ -534,6 +708,11 @@ export default function Dashboard() {
                   const service = parseEntityFields(WasmService, serviceFields);
 
                   Promise.resolve()
+                    .then(
+                      () =>
+                        upstreams &&
+                        service.check_upstream_references(upstreams.ids),
+                    )
                     .then(() =>
                       upsertService(service, getIsEditing(WasmService)),
                     )
@@ -576,6 +755,16 @@ export default function Dashboard() {
                     setServiceFields(prepareEdit(service, WasmService));
                     setFormOpened(WasmService)(true, true);
                   }}
+                  onPromote={
+                    canPromote
+                      ? (service) => {
+                          setPromotion({
+                            entity: "Service",
+                            id: String(service.get_field("id")),
+                          });
+                        }
+                      : undefined
+                  }
                 />
               )}
             </Section>
@@ -586,6 +775,11 @@ export default function Dashboard() {
                     Stream Routes
                     {streamRoutes ? ` (${streamRoutes.total})` : ""}
                   </span>
+                  {!WasmStreamRoute.is_supported(apisixVersion) && (
+                    <span className="text-[12px] text-[#f90]">
+                      Requires APISIX {WasmStreamRoute.required_version}
+                    </span>
+                  )}
                   <AdminDocs model={WasmStreamRoute} />
                 </h2>
               }
@@ -704,9 +898,27 @@ export default function Dashboard() {
                     });
                   }}
                   onEdit={(upstream) => {
-                    setUpstreamFields(prepareEdit(upstream, WasmUpstream));
+                    const fields = prepareEdit(upstream, WasmUpstream);
+                    // The nodes are edited as a list, even when APISIX stores them as a hash table
+                    const nodes = upstream.get_nodes() as unknown[];
+
+                    if (nodes.length) {
+                      fields.nodes = JSON.stringify(nodes);
+                    }
+
+                    setUpstreamFields(fields);
                     setFormOpened(WasmUpstream)(true, true);
                   }}
+                  onPromote={
+                    canPromote
+                      ? (upstream) => {
+                          setPromotion({
+                            entity: "Upstream",
+                            id: String(upstream.get_field("id")),
+                          });
+                        }
+                      : undefined
+                  }
                 />
               )}
             </Section>
@@ -725,6 +937,16 @@ export default function Dashboard() {
             </div>
           )}
         </div>
+        <PromotionDialog
+          onApplied={(changes) => {
+            setPromotion(null);
+            setSnackbar(`Applied ${changes} changes`);
+          }}
+          onClose={() => setPromotion(null)}
+          onError={setSnackbar}
+          promotion={promotion}
+          serverInfo={serverInfo}
+        />
         <Snackbar
           anchorOrigin={{ horizontal: "center", vertical: "top" }}
           key="top"
@@ -1,6 +1,7 @@
 import { memo } from "react";
 import type { EntityFields } from "src/bindings/EntityFields";
 import type { PluginEntities } from "src/bindings/PluginEntities";
+import { isJSONProperty } from "src/lib/parsing";
 
 import { MultiField } from "./MultiField";
 import { parsePlugins, PluginsField } from "./PluginsField";
@@ -21,6 +22,8 @@ const extraField: EntityFields = {
   is_required: "False",
   name: "extra",
   property_type: "JSON",
+  sensitive: false,
+  version_range: null,
 };
 
 export const parseEntityFields = <
@@ -109,10 +112,23 @@ export const parseEntityFields = <
       }
 
       default: {
+        if (isJSONProperty(fieldDefinition.property_type)) {
+          const value = (() => {
+            try {
+              return JSON.parse(itemValue as string);
+            } catch {
+              return undefined;
+            }
+          })();
+
+          entity.set_field(itemName, value);
+          break;
+        }
+
         if (
           typeof fieldDefinition.property_type === "object" &&
           "List" in fieldDefinition.property_type &&
-          fieldDefinition.property_type.List === "JSON"
+          isJSONProperty(fieldDefinition.property_type.List)
         ) {
           if (!itemValue) {
             break;
@@ -1,7 +1,8 @@
 import Head from "next/head";
 import { useRouter } from "next/router";
-import { useState } from "react";
-import { login } from "src/lib/client";
+import { useEffect, useState } from "react";
+import type { AuthOptions } from "src/bindings/AuthOptions";
+import { getAuthOptions, getOidcLoginUrl, login } from "src/lib/client";
 import { useSnackbar } from "src/lib/use-snackbar";
 
 import Snackbar from "@mui/material/Snackbar";
@@ -15,8 +16,18 @@ const labelClasses = "flex flex-col gap-[8px]";
 const LogIn = () => {
   const [username, setUsername] = useState("");
   const [password, setPassword] = useState("");
+  const [authOptions, setAuthOptions] = useState<AuthOptions | null>(null);
   const router = useRouter();
 
+  useEffect(() => {
+    getAuthOptions()
+      .then(setAuthOptions)
+      .catch(() => setAuthOptions(null));
+  }, []);
+
+  // The form is kept when the options can't be loaded
+  const hasPassword = authOptions?.has_password ?? true;
+
   const { setSnackbar, setSnackbarOpen, snackbarMessage, snackbarOpen } =
     useSnackbar();
 
@@ -28,39 +39,46 @@ const LogIn = () => {
       <Layout>
         <div className="flex flex-col items-center justify-center">
           <h2>Log in to the panel</h2>
-          <form
-            className="flex max-w-[300px] flex-col gap-[24px]"
-            onSubmit={(e) => {
-              e.preventDefault();
+          {authOptions?.has_oidc && (
+            <Button className="mb-[24px]" href={getOidcLoginUrl()}>
+              Log in with SSO
+            </Button>
+          )}
+          {hasPassword && (
+            <form
+              className="flex max-w-[300px] flex-col gap-[24px]"
+              onSubmit={(e) => {
+                e.preventDefault();
 
-              login(username, password).then((isLoggedIn) => {
-                if (isLoggedIn) {
-                  router.replace("/");
-                } else {
-                  setSnackbar("Invalid username or password");
-                }
-              });
-            }}
-          >
-            <label className={labelClasses}>
-              <span>Username:</span>
-              <Input
-                name="username"
-                onChange={(e) => setUsername(e.target.value)}
-                value={username}
-              />
-            </label>
-            <label className={labelClasses}>
-              <span>Password:</span>
-              <Input
-                name="password"
-                onChange={(e) => setPassword(e.target.value)}
-                type="password"
-                value={password}
-              />
-            </label>
-            <Button type="submit">Log In</Button>
-          </form>
+                login(username, password).then((isLoggedIn) => {
+                  if (isLoggedIn) {
+                    router.replace("/");
+                  } else {
+                    setSnackbar("Invalid username or password");
+                  }
+                });
+              }}
+            >
+              <label className={labelClasses}>
+                <span>Username:</span>
+                <Input
+                  name="username"
+                  onChange={(e) => setUsername(e.target.value)}
+                  value={username}
+                />
+              </label>
+              <label className={labelClasses}>
+                <span>Password:</span>
+                <Input
+                  name="password"
+                  onChange={(e) => setPassword(e.target.value)}
+                  type="password"
+                  value={password}
+                />
+              </label>
+              <Button type="submit">Log In</Button>
+            </form>
+          )}
         </div>
         <Snackbar
           anchorOrigin={{ horizontal: "center", vertical: "top" }}
@@ -1,6 +1,7 @@
 import { useRef } from "react";
 import type { PropertyType } from "src/bindings/PropertyType";
 import type { Required } from "src/bindings/Required";
+import { getCodeLanguage, isJSONProperty } from "src/lib/parsing";
 
 import FormControl from "@mui/material/FormControl";
 import InputLabel from "@mui/material/InputLabel";
@@ -21,8 +22,10 @@ type Definition = {
   example?: null | string;
   is_editable?: boolean;
   is_required: Required;
+  is_secret?: boolean;
   name: string;
   property_type: PropertyType;
+  sensitive?: boolean;
 };
 
 type Props = {
@@ -67,7 +70,10 @@ export const MultiField = ({
       return (existingOptionValue as string[] | undefined) || [];
     })() as string[];
 
-    const isJSON = propertyType.List === "JSON";
+    const isJSON = isJSONProperty(propertyType.List);
+    const codeLanguage = getCodeLanguage(propertyType.List);
+    const labelSuffix =
+      (isJSON ? " (JSON)" : "") + (codeLanguage ? ` (${codeLanguage})` : "");
 
     return (
       <div className="flex flex-col gap-[12px]">
@@ -85,7 +91,7 @@ export const MultiField = ({
           </Button>
         </div>
         {parsedItems.map((value, index) => {
-          const fieldLabel = `#${index + 1}${isJSON ? " (JSON)" : ""}`;
+          const fieldLabel = `#${index + 1}${labelSuffix}`;
 
           const isValid = (() => {
             if (!isJSON || !value) {
@@ -106,7 +112,8 @@ export const MultiField = ({
               <Input
                 error={!isValid}
                 label={fieldLabel}
-                multiline={isJSON}
+                minRows={codeLanguage ? 4 : undefined}
+                multiline={isJSON || !!codeLanguage}
                 onChange={(e) => {
                   setState({
                     ...state,
@@ -241,9 +248,10 @@ export const MultiField = ({
     );
   }
 
-  const isJSON = propertyType === "JSON";
+  const isJSON = isJSONProperty(propertyType);
+  const codeLanguage = getCodeLanguage(propertyType);
 
-  if (propertyType === "JSON") {
+  if (isJSON) {
     setContentWrap.current.fn = (val: string) => {
       setState({
         ...state,
@@ -281,8 +289,16 @@ export const MultiField = ({
       error={!isValid}
       helperText={description}
       key={name}
-      label={name + (isJSON ? " (JSON)" : "")}
-      multiline={isJSON}
+      label={
+        name +
+        (isJSON ? " (JSON)" : "") +
+        (codeLanguage ? ` (${codeLanguage})` : "") +
+        (definition.is_secret ? " (supports $secret://)" : "") +
+        // The server masks the stored value, sending the mask back keeps it
+        (definition.sensitive ? " (masked)" : "")
+      }
+      minRows={codeLanguage ? 4 : undefined}
+      multiline={isJSON || !!codeLanguage}
       onChange={(e) => {
         setState({
           ...state,
@@ -4,6 +4,7 @@ import { memo } from "react";
 import type { PluginDefinition } from "../bindings/PluginDefinition";
 import type { PluginEntities } from "../bindings/PluginEntities";
 import type { PluginOption } from "../bindings/PluginOption";
+import { isJSONProperty, isRequiredValue } from "../lib/parsing";
 
 import { MultiField } from "./MultiField";
 import { Checkbox } from "./ui/Input";
@@ -21,14 +22,21 @@ type Props = {
   setPlugins: (plugins: PluginsState) => void;
 };
 
-const pluginsDefinitions = (() => {
+const printPluginsDefinitions = () => {
   if (typeof window === "undefined") {
     return [];
   }
 
   // eslint-disable-next-line @typescript-eslint/no-unnecessary-type-assertion
   return WasmPluginDefinitions.print() as PluginDefinition[];
-})();
+};
+
+let pluginsDefinitions = printPluginsDefinitions();
+
+// After loading the APISIX schema, the definitions include every enabled plugin
+export const refreshPluginsDefinitions = () => {
+  pluginsDefinitions = printPluginsDefinitions();
+};
 
 export const parsePlugins = (
   entity: PluginEntities,
@@ -52,7 +60,10 @@ export const parsePlugins = (
               const { name: pluginOption } = pluginDefOpt;
               const { [pluginOption]: pluginOptionValue } = plugin;
 
-              if (pluginDefOpt.is_required !== "False" && !pluginOptionValue) {
+              if (
+                isRequiredValue(pluginDefOpt.is_required, plugin) &&
+                !pluginOptionValue
+              ) {
                 throw `Required field ${pluginName}.${pluginOption} is empty`;
               }
 
@@ -96,7 +107,8 @@ export const parsePlugins = (
                 default: {
                   if (
                     typeof pluginDefOpt.property_type === "object" &&
-                    "Enum" in pluginDefOpt.property_type
+                    ("Enum" in pluginDefOpt.property_type ||
+                      "Code" in pluginDefOpt.property_type)
                   ) {
                     pluginVal[pluginOption] = pluginOptionValue;
                     break;
@@ -106,7 +118,7 @@ export const parsePlugins = (
                     typeof pluginDefOpt.property_type === "object" &&
                     "List" in pluginDefOpt.property_type
                   ) {
-                    if (pluginDefOpt.property_type.List === "JSON") {
+                    if (isJSONProperty(pluginDefOpt.property_type.List)) {
                       pluginVal[pluginOption] = (() => {
                         try {
                           return (pluginOptionValue as string[])
@@ -129,6 +141,22 @@ export const parsePlugins = (
                     break;
                   }
 
+                  if (
+                    typeof pluginDefOpt.property_type === "object" &&
+                    ("Object" in pluginDefOpt.property_type ||
+                      "Map" in pluginDefOpt.property_type)
+                  ) {
+                    pluginVal[pluginOption] = (() => {
+                      try {
+                        return JSON.parse(pluginOptionValue as string);
+                      } catch {
+                        return undefined;
+                      }
+                    })();
+
+                    break;
+                  }
+
                   pluginDefOpt.property_type satisfies never;
                   pluginVal[pluginOption] = pluginOptionValue;
                 }
@@ -148,6 +176,29 @@ export const parsePlugins = (
     undefined as Record<string, unknown> | undefined,
   );
 
+// `_meta` is kept as a JSON string in the state, like the other object options
+const parseMeta = (meta: string | string[] | undefined) => {
+  try {
+    const parsed: unknown = JSON.parse(meta as string);
+
+    return typeof parsed === "object" && parsed
+      ? (parsed as Record<string, unknown>)
+      : {};
+  } catch {
+    return {};
+  }
+};
+
+const toggleMetaDisable = (
+  meta: string | string[] | undefined,
+  disabled: boolean,
+) => {
+  const { disable: _disable, ...rest } = parseMeta(meta);
+  const newMeta = disabled ? { ...rest, disable: true } : rest;
+
+  return Object.keys(newMeta).length ? JSON.stringify(newMeta) : undefined;
+};
+
 const PluginsFieldBase = ({
   entity,
   isEditing,
@@ -197,7 +248,12 @@ const PluginsFieldBase = ({
               return 2;
             };
 
+            const isDisabled =
+              isEnabled &&
+              parseMeta(existingPluginValues?._meta).disable === true;
+
             const checkboxId = [entity, name, "enabled"].join("-");
+            const disabledCheckboxId = [entity, name, "disabled"].join("-");
 
             return (
               <div
@@ -224,6 +280,32 @@ const PluginsFieldBase = ({
                     <CodeIcon />
                   </a>
                   <div className="flex-1" />
+                  {!!isEnabled && (
+                    <span title="Keeps the config but the plugin is not executed">
+                      <label
+                        className="cursor-pointer"
+                        htmlFor={disabledCheckboxId}
+                      >
+                        Disabled:
+                      </label>{" "}
+                      <Checkbox
+                        checked={isDisabled}
+                        id={disabledCheckboxId}
+                        onChange={() => {
+                          setPlugins({
+                            ...(plugins || {}),
+                            [name]: {
+                              ...existingPluginValues,
+                              _meta: toggleMetaDisable(
+                                existingPluginValues?._meta,
+                                !isDisabled,
+                              ),
+                            },
+                          } as PluginsState);
+                        }}
+                      />
+                    </span>
+                  )}
                   <span>
                     <label className="cursor-pointer" htmlFor={checkboxId}>
                       Enable:
@@ -0,0 +1,151 @@
+import { useEffect, useRef, useState } from "react";
+
+import Dialog from "@mui/material/Dialog";
+import DialogActions from "@mui/material/DialogActions";
+import DialogContent from "@mui/material/DialogContent";
+import DialogTitle from "@mui/material/DialogTitle";
+import MenuItem from "@mui/material/MenuItem";
+import Select from "@mui/material/Select";
+
+import type { PromotionChange } from "../bindings/PromotionChange";
+import type { PromotionEntity } from "../bindings/PromotionEntity";
+import type { ServerInfo } from "../bindings/ServerInfo";
+import { promote } from "../lib/client";
+
+import Button from "./ui/Button";
+
+export type PromotionTarget = {
+  entity: PromotionEntity;
+  id: string;
+};
+
+type Props = {
+  onApplied: (changes: number) => void;
+  onClose: () => void;
+  onError: (err: unknown) => void;
+  promotion: PromotionTarget | null;
+  serverInfo: ServerInfo | undefined;
+};
+
+const formatValue = (value: unknown) =>
+  value === undefined || value === null ? "-" : JSON.stringify(value);
+
+const ChangeItem = ({ change }: { change: PromotionChange }) => (
+  <div className="flex flex-col gap-[4px]">
+    <span>
+      {change.action} {change.entity} {change.id}
+    </span>
+    {change.diff.map((diff) => (
+      <code className="pl-[12px] text-[12px]" key={JSON.stringify(diff.path)}>
+        {diff.path.join(".")}: {formatValue(diff.target)} →{" "}
+        {formatValue(diff.source)}
+      </code>
+    ))}
+    {change.unresolved.map((reference) => (
+      <code className="pl-[12px] text-[12px]" key={reference}>
+        Not promoted, must exist in the target: {reference}
+      </code>
+    ))}
+  </div>
+);
+
+export const PromotionDialog = ({
+  onApplied,
+  onClose,
+  onError,
+  promotion,
+  serverInfo,
+}: Props) => {
+  const targets = (serverInfo?.clusters || []).filter(
+    (c) => c.name !== serverInfo?.cluster,
+  );
+
+  const [target, setTarget] = useState("");
+  const [changes, setChanges] = useState<PromotionChange[] | null>(null);
+  const [isApplying, setIsApplying] = useState(false);
+
+  const selectedTarget = target || targets[0]?.name || "";
+
+  // The callback changes in every render of the parent, it shouldn't refetch the preview
+  const onErrorRef = useRef(onError);
+  onErrorRef.current = onError;
+
+  // The preview is refreshed when the entity or the target change
+  useEffect(() => {
+    setChanges(null);
+
+    if (!promotion || !selectedTarget) {
+      return;
+    }
+
+    promote({ ...promotion, apply: false, target: selectedTarget })
+      .then((res) => setChanges(res.changes))
+      .catch((err: unknown) => onErrorRef.current(err));
+  }, [promotion, selectedTarget]);
+
+  const pendingChanges = (changes || []).filter(
+    (c) => c.action !== "Unchanged",
+  );
+
+  return (
+    <Dialog fullWidth maxWidth="md" onClose={onClose} open={!!promotion}>
+      <DialogTitle>
+        Promote {promotion?.entity} {promotion?.id}
+      </DialogTitle>
+      <DialogContent>
+        {!targets.length ? (
+          <div>There are no other clusters to promote to.</div>
+        ) : (
+          <div className="flex flex-col gap-[12px]">
+            <div className="flex flex-row items-center gap-[12px]">
+              <label htmlFor="promotion-target">Target cluster:</label>
+              <Select
+                id="promotion-target"
+                onChange={(e) => {
+                  setTarget(e.target.value);
+                }}
+                value={selectedTarget}
+              >
+                {targets.map((cluster) => (
+                  <MenuItem key={cluster.name} value={cluster.name}>
+                    {cluster.name}
+                  </MenuItem>
+                ))}
+              </Select>
+            </div>
+            {changes === null ? (
+              <div>Loading...</div>
+            ) : (
+              changes.map((change) => (
+                <ChangeItem
+                  change={change}
+                  key={`${change.entity}-${change.id}`}
+                />
+              ))
+            )}
+          </div>
+        )}
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={onClose}>Close</Button>
+        <Button
+          disabled={!pendingChanges.length || isApplying}
+          onClick={() => {
+            if (!promotion) {
+              return;
+            }
+
+            setIsApplying(true);
+
+            promote({ ...promotion, apply: true, target: selectedTarget })
+              .then(() => onApplied(pendingChanges.length))
+              .catch(onError)
+              .finally(() => setIsApplying(false));
+          }}
+        >
+          Apply {pendingChanges.length} changes
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
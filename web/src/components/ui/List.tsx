@@ -3,7 +3,9 @@ import DeleteIcon from "@mui/icons-material/Delete";
 import EditIcon from "@mui/icons-material/Edit";
 import ExpandMoreIcon from "@mui/icons-material/ExpandMore";
 import type FolderIcon from "@mui/icons-material/Folder";
+import LayersIcon from "@mui/icons-material/Layers";
 import OpenInNew from "@mui/icons-material/OpenInNew";
+import PublishIcon from "@mui/icons-material/Publish";
 import Accordion from "@mui/material/Accordion";
 import AccordionDetails from "@mui/material/AccordionDetails";
 import AccordionSummary from "@mui/material/AccordionSummary";
@@ -23,7 +25,9 @@ type ListProps<A extends ListItemBase> = {
   items: A[];
   onDelete: (item: A) => void;
   onEdit: (item: A) => void;
+  onInspectPlugins?: (item: A) => void;
   onOpenUrl?: (item: A) => void;
+  onPromote?: (item: A) => void;
 };
 
 export const EntitiesList = <A extends ListItemBase>({
@@ -31,7 +35,9 @@ export const EntitiesList = <A extends ListItemBase>({
   items,
   onDelete,
   onEdit,
+  onInspectPlugins,
   onOpenUrl,
+  onPromote,
 }: ListProps<A>) => (
   <List>
     {items.map((item) => (
@@ -60,6 +66,30 @@ export const EntitiesList = <A extends ListItemBase>({
                 <OpenInNew />
               </IconButton>
             )}
+            {onInspectPlugins && (
+              <IconButton
+                aria-label="Effective plugins"
+                edge="end"
+                onClick={(e) => {
+                  e.stopPropagation();
+                  onInspectPlugins(item);
+                }}
+              >
+                <LayersIcon />
+              </IconButton>
+            )}
+            {onPromote && (
+              <IconButton
+                aria-label="Promote"
+                edge="end"
+                onClick={(e) => {
+                  e.stopPropagation();
+                  onPromote(item);
+                }}
+              >
+                <PublishIcon />
+              </IconButton>
+            )}
             <IconButton
               aria-label="Edit"
               edge="end"
@@ -3,50 +3,106 @@ import {
   WasmConsumer,
   WasmConsumerGroup,
   WasmControlPlane,
+  WasmPluginDefinitions,
   WasmRoute,
   WasmSecret,
   WasmService,
   WasmStreamRoute,
   WasmUpstream,
 } from "pkg";
+import type { AuthOptions } from "src/bindings/AuthOptions";
+import type { ControlHealthCheck } from "src/bindings/ControlHealthCheck";
+import type { ControlItem } from "src/bindings/ControlItem";
+import type { PromotionRequest } from "src/bindings/PromotionRequest";
+import type { PromotionResponse } from "src/bindings/PromotionResponse";
 import type { ServerInfo } from "src/bindings/ServerInfo";
 
 const baseUrl =
   process.env.NODE_ENV === "production" ? "" : "http://localhost:9000";
 
+const clusterStorageKey = "cluster";
+
+export const getSelectedCluster = () =>
+  typeof window === "undefined"
+    ? null
+    : window.localStorage.getItem(clusterStorageKey);
+
+// The whole page is reloaded, so no data of the previous cluster is kept
+export const selectCluster = (cluster: string) => {
+  window.localStorage.setItem(clusterStorageKey, cluster);
+  window.location.reload();
+};
+
+// The server sets the cookie, and requires it in the header of the POST requests
+const getCsrfToken = () =>
+  document.cookie
+    .split("; ")
+    .find((c) => c.startsWith("csrf_token="))
+    ?.slice("csrf_token=".length);
+
+// Any response of the server sets the cookie, like the one of the page when it is served by it
+const ensureCsrfToken = async () => {
+  if (!getCsrfToken()) {
+    await fetch(`${baseUrl}/api/health`, { credentials: "include" });
+  }
+};
+
+const getHeaders = () => {
+  const cluster = getSelectedCluster();
+  const csrfToken = getCsrfToken();
+
+  return {
+    "Content-Type": "application/json",
+    ...(cluster ? { "X-APISIX-Cluster": cluster } : {}),
+    ...(csrfToken ? { "X-CSRF-Token": csrfToken } : {}),
+  };
+};
+
 const verifyResponse = (res: Response) => {
   if (res.status === 401) {
     window.location.href = "/login";
   }
 };
 
-const fetchApisixAdmin = async <T>(body: WasmProxyFetchOpts) =>
-  await fetch(`${baseUrl}/api/apisix-admin`, {
+const fetchApisixAdmin = async <T>(body: WasmProxyFetchOpts) => {
+  await ensureCsrfToken();
+
+  return await fetch(`${baseUrl}/api/apisix-admin`, {
     body: body.format(),
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
     method: "POST",
-  }).then((res) => {
+  }).then(async (res) => {
     verifyResponse(res);
 
+    // The server explains why it rejected the request, like an unknown uri
+    if (res.status === 403) {
+      throw await res.text();
+    }
+
     return res.json() as T;
   });
+};
+
+const fetchApisixControl = async <T>(body: WasmProxyFetchOpts) => {
+  await ensureCsrfToken();
 
-const fetchApisixControl = async <T>(body: WasmProxyFetchOpts) =>
-  await fetch(`${baseUrl}/api/apisix-control`, {
+  return await fetch(`${baseUrl}/api/apisix-control`, {
     body: body.format(),
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
     method: "POST",
-  }).then((res) => {
+  }).then(async (res) => {
     verifyResponse(res);
 
+    // The errors of APISIX and the rejected uris are returned as text
+    if (!res.ok) {
+      throw await res.text();
+    }
+
     return res.json() as T;
   });
+};
 
 const genGetMethod =
   <
@@ -117,55 +173,124 @@ export const upsertService = upsertMethod(WasmService);
 export const upsertStreamRoute = upsertMethod(WasmStreamRoute);
 export const upsertUpstream = upsertMethod(WasmUpstream);
 
+export const getPluginConfigs = () =>
+  fetchApisixAdmin(WasmPluginDefinitions.get_plugin_configs());
+
 export const getSchema = () =>
   fetchApisixControl(WasmControlPlane.get_schema());
+// Falls back to the static plugin definitions when the schema is not available
+export const loadSchema = () =>
+  getSchema()
+    .then((schema) => WasmControlPlane.load_schema(schema))
+    .catch(() => undefined);
 export const getHealthCheck = () =>
-  fetchApisixControl(WasmControlPlane.get_health_check());
+  fetchApisixControl<ControlHealthCheck[]>(
+    WasmControlPlane.get_health_check(),
+  );
+export const getEntityHealthCheck = (srcType: string, id: string) =>
+  fetchApisixControl<ControlHealthCheck>(
+    WasmControlPlane.get_entity_health_check(srcType, id),
+  );
+export const getControlRoutes = () =>
+  fetchApisixControl<ControlItem[]>(WasmControlPlane.get_routes());
+export const getControlRoute = (id: string) =>
+  fetchApisixControl<ControlItem>(WasmControlPlane.get_route(id));
+export const getControlServices = () =>
+  fetchApisixControl<ControlItem[]>(WasmControlPlane.get_services());
+export const getControlService = (id: string) =>
+  fetchApisixControl<ControlItem>(WasmControlPlane.get_service(id));
+export const getControlUpstreams = () =>
+  fetchApisixControl<ControlItem[]>(WasmControlPlane.get_upstreams());
+export const getControlUpstream = (id: string) =>
+  fetchApisixControl<ControlItem>(WasmControlPlane.get_upstream(id));
+export const getPluginMetadatas = () =>
+  fetchApisixControl<unknown[]>(WasmControlPlane.get_plugin_metadatas());
+export const getDiscoveryDump = (discovery: string) =>
+  fetchApisixControl(WasmControlPlane.get_discovery_dump(discovery));
+export const runGc = () => fetchApisixControl(WasmControlPlane.run_gc());
 export const reloadPlugins = () =>
   fetchApisixControl(WasmControlPlane.reload_plugins());
 export const getFileConfig = () =>
   fetch(`${baseUrl}/api/apisix-config`, {
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
   }).then((res) => {
     verifyResponse(res);
 
     return res.json() as unknown;
   });
+// Adds the proxy-wasm plugins declared in the file config, when it is mounted
+export const loadFileConfig = () =>
+  getFileConfig()
+    .then((config) => WasmPluginDefinitions.load_file_config(config))
+    .catch(() => undefined);
 
 export const getServerInfo = async (): Promise<ServerInfo> =>
   fetch(`${baseUrl}/api/info`, {
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
   }).then((res) => {
     verifyResponse(res);
 
+    // The selected cluster was removed or the user can't access it anymore,
+    // the other requests can also be forbidden by the role of the user
+    if (res.status === 403 && getSelectedCluster()) {
+      window.localStorage.removeItem(clusterStorageKey);
+      window.location.reload();
+    }
+
     return res.json() as unknown as ServerInfo;
   });
 
+// Without `apply`, it only returns the changes compared with the target cluster
+export const promote = async (
+  request: PromotionRequest,
+): Promise<PromotionResponse> => {
+  await ensureCsrfToken();
+
+  return fetch(`${baseUrl}/api/promotion`, {
+    body: JSON.stringify(request),
+    credentials: "include",
+    headers: getHeaders(),
+    method: "POST",
+  }).then(async (res) => {
+    verifyResponse(res);
+
+    if (!res.ok) {
+      throw await res.text();
+    }
+
+    return res.json() as unknown as PromotionResponse;
+  });
+};
+
 export const login = async (username: string, password: string) => {
+  await ensureCsrfToken();
+
   const response = await fetch(`${baseUrl}/auth/login`, {
     body: JSON.stringify({ password, username }),
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
     method: "POST",
   });
 
   return response.ok;
 };
 
+export const getAuthOptions = async (): Promise<AuthOptions> =>
+  fetch(`${baseUrl}/auth/options`, { credentials: "include" }).then(
+    (res) => res.json() as unknown as AuthOptions,
+  );
+
+// The IdP redirects back to the server, which redirects to the panel after the login
+export const getOidcLoginUrl = () => `${baseUrl}/auth/oidc/login`;
+
 export const logout = async () => {
+  await ensureCsrfToken();
+
   const response = await fetch(`${baseUrl}/auth/logout`, {
     credentials: "include",
-    headers: {
-      "Content-Type": "application/json",
-    },
+    headers: getHeaders(),
     method: "POST",
   });
 
@@ -1,4 +1,31 @@
 import type { EntityFields } from "src/bindings/EntityFields";
+import type { PropertyType } from "src/bindings/PropertyType";
+import type { Required } from "src/bindings/Required";
+
+export const isJSONProperty = (propertyType: PropertyType) =>
+  propertyType === "JSON" ||
+  (typeof propertyType === "object" &&
+    ("Object" in propertyType || "Map" in propertyType));
+
+export const getCodeLanguage = (propertyType: PropertyType) =>
+  typeof propertyType === "object" && "Code" in propertyType
+    ? propertyType.Code
+    : undefined;
+
+export const isRequiredValue = (
+  required: Required,
+  values: Record<string, unknown>,
+) => {
+  if (typeof required === "object") {
+    return required.TrueIfOtherMissing.every((other) => !values[other]);
+  }
+
+  return required === "True";
+};
+
+// Raw admin API items of a list response, e.g. for the effective plugins of a route
+export const listValues = (list: { text: string }[] | undefined) =>
+  (list || []).map((item) => JSON.parse(item.text) as unknown);
 
 const knownFieldsToDelete = ["create_time", "update_time", "priority"];
 
@@ -22,8 +49,9 @@ export const prepareEdit = (
     if (key === "plugins") {
       parsed.plugins = Object.entries(parsed.plugins).reduce(
         (acc, [pluginName, pluginValue]) => {
+          // Plugins without options can be null in the standalone config
           const newPluginValue = Object.entries(
-            pluginValue as Record<string, unknown>,
+            (pluginValue || {}) as Record<string, unknown>,
           ).reduce(
             (acc2, [pluginOption, pluginOptionValue]) => {
               if (typeof pluginOptionValue === "object") {